@@ -0,0 +1,370 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{BigUInt, ParseBigUIntError, TryFromBigIntError};
+
+/// Arbitrary-precision signed integer.
+///
+/// Represented by a sign and a `BigUInt` magnitude, zero is never negative.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUInt,
+}
+
+impl BigInt {
+    /// Builds a `BigInt` from a sign and a magnitude.
+    pub fn new(negative: bool, magnitude: BigUInt) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Checks if the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Checks if the number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Checks if the number is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    /// Get the absolute value of the number as a `BigUInt`.
+    pub fn magnitude(&self) -> &BigUInt {
+        &self.magnitude
+    }
+
+    /// Get the absolute value of the number.
+    pub fn abs(&self) -> Self {
+        Self::new(false, self.magnitude.clone())
+    }
+
+    /// Get `-1`, `0` or `1` depending on the sign of the number.
+    pub fn signum(&self) -> i64 {
+        if self.negative {
+            -1
+        } else if self.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Raises the number to the power of `n`.
+    pub fn pow(self, n: u64) -> Self {
+        Self::new(self.negative && n % 2 == 1, self.magnitude.pow(n))
+    }
+
+    /// Calculates the truncated quotient and remainder of the division of `self` by `rhs`.
+    ///
+    /// The quotient is rounded towards zero and the remainder has the same sign as `self`,
+    /// matching the behaviour of the primitive integers.
+    ///
+    /// # Panic
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.div_rem(&rhs.magnitude);
+        (
+            Self::new(self.negative != rhs.negative, quotient),
+            Self::new(self.negative, remainder),
+        )
+    }
+
+    /// Calculates the Euclidean quotient and remainder of the division of `self` by `rhs`.
+    ///
+    /// The remainder is always in the range `0..|rhs|`.
+    ///
+    /// # Panic
+    /// Panics if `rhs` is zero.
+    pub fn div_rem_euclid(&self, rhs: &BigInt) -> (Self, Self) {
+        let (quotient, remainder) = self.div_rem(rhs);
+        if remainder.negative {
+            let one = BigInt::from(1_i64);
+            let quotient = if rhs.negative {
+                quotient + one
+            } else {
+                quotient - one
+            };
+            (quotient, remainder + rhs.abs())
+        } else {
+            (quotient, remainder)
+        }
+    }
+
+    /// Calculates the Euclidean quotient of the division of `self` by `rhs`.
+    pub fn div_euclid(&self, rhs: &BigInt) -> Self {
+        self.div_rem_euclid(rhs).0
+    }
+
+    /// Calculates the Euclidean remainder of the division of `self` by `rhs`.
+    pub fn rem_euclid(&self, rhs: &BigInt) -> Self {
+        self.div_rem_euclid(rhs).1
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigUIntError;
+
+    /// Parses a decimal number with an optional leading `+` or `-` sign.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(magnitude) => Ok(Self::new(true, magnitude.parse()?)),
+            None => Ok(Self::new(false, s.strip_prefix('+').unwrap_or(s).parse()?)),
+        }
+    }
+}
+
+impl From<BigUInt> for BigInt {
+    fn from(magnitude: BigUInt) -> Self {
+        Self::new(false, magnitude)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        Self::new(n < 0, BigUInt::from(n.unsigned_abs()))
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        Self::new(n < 0, BigUInt::from(n.unsigned_abs()))
+    }
+}
+
+impl TryFrom<BigInt> for BigUInt {
+    type Error = TryFromBigIntError;
+
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        if value.negative {
+            Err(TryFromBigIntError)
+        } else {
+            Ok(value.magnitude)
+        }
+    }
+}
+
+impl TryFrom<&BigInt> for i128 {
+    type Error = TryFromBigIntError;
+
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        u128::try_from(&value.magnitude)
+            .ok()
+            .and_then(|m| {
+                if value.negative {
+                    0_i128.checked_sub_unsigned(m)
+                } else {
+                    i128::try_from(m).ok()
+                }
+            })
+            .ok_or(TryFromBigIntError)
+    }
+}
+
+impl TryFrom<&BigInt> for i64 {
+    type Error = TryFromBigIntError;
+
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        i128::try_from(value)
+            .ok()
+            .and_then(|n| i64::try_from(n).ok())
+            .ok_or(TryFromBigIntError)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for BigInt {
+    type Output = Self;
+
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            Self::new(self.negative, self.magnitude + &rhs.magnitude)
+        } else if self.magnitude >= rhs.magnitude {
+            Self::new(self.negative, self.magnitude - &rhs.magnitude)
+        } else {
+            Self::new(rhs.negative, rhs.magnitude.clone() - self.magnitude)
+        }
+    }
+}
+
+impl Add<BigInt> for BigInt {
+    type Output = Self;
+
+    fn add(self, rhs: BigInt) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl AddAssign<BigInt> for BigInt {
+    fn add_assign(&mut self, rhs: BigInt) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl Sub<&BigInt> for BigInt {
+    type Output = Self;
+
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + -rhs.clone()
+    }
+}
+
+impl Sub<BigInt> for BigInt {
+    type Output = Self;
+
+    fn sub(self, rhs: BigInt) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl SubAssign<BigInt> for BigInt {
+    fn sub_assign(&mut self, rhs: BigInt) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl Mul<&BigInt> for BigInt {
+    type Output = Self;
+
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        Self::new(
+            self.negative != rhs.negative,
+            self.magnitude * rhs.magnitude.clone(),
+        )
+    }
+}
+
+impl Mul<BigInt> for BigInt {
+    type Output = Self;
+
+    fn mul(self, rhs: BigInt) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Div<&BigInt> for BigInt {
+    type Output = Self;
+
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Div<BigInt> for BigInt {
+    type Output = Self;
+
+    fn div(self, rhs: BigInt) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl Rem<&BigInt> for BigInt {
+    type Output = Self;
+
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Rem<BigInt> for BigInt {
+    type Output = Self;
+
+    fn rem(self, rhs: BigInt) -> Self::Output {
+        self % &rhs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic_test() {
+        for l in -12_i64..=12 {
+            for r in -12_i64..=12 {
+                let (bl, br) = (BigInt::from(l), BigInt::from(r));
+                assert_eq!(bl.clone() + &br, BigInt::from(l + r));
+                assert_eq!(bl.clone() - &br, BigInt::from(l - r));
+                assert_eq!(bl.clone() * &br, BigInt::from(l * r));
+                assert_eq!(bl.cmp(&br), l.cmp(&r));
+                if r != 0 {
+                    assert_eq!(bl.clone() / &br, BigInt::from(l / r));
+                    assert_eq!(bl.clone() % &br, BigInt::from(l % r));
+                    assert_eq!(bl.div_euclid(&br), BigInt::from(l.div_euclid(r)));
+                    assert_eq!(bl.rem_euclid(&br), BigInt::from(l.rem_euclid(r)));
+                }
+            }
+        }
+        assert_eq!(-BigInt::from(0_i64), BigInt::from(0_i64));
+        assert_eq!(BigInt::from(-3_i64).pow(3), BigInt::from(-27_i64));
+    }
+
+    #[test]
+    fn conversion_test() {
+        assert_eq!(i64::try_from(&BigInt::from(i64::MIN)), Ok(i64::MIN));
+        assert_eq!(i128::try_from(&BigInt::from(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(i128::try_from(&BigInt::from(i128::MAX)), Ok(i128::MAX));
+        assert!(i64::try_from(&BigInt::from(i128::from(i64::MAX) + 1)).is_err());
+        assert!(i128::try_from(&-BigInt::from(i128::MIN)).is_err());
+        assert_eq!(
+            BigUInt::try_from(BigInt::from(5_i64)),
+            Ok(BigUInt::from(5_u64))
+        );
+        assert_eq!(
+            BigUInt::try_from(BigInt::from(-5_i64)),
+            Err(TryFromBigIntError)
+        );
+    }
+
+    #[test]
+    fn display_from_str_test() {
+        assert_eq!(BigInt::from(-42_i64).to_string(), "-42");
+        assert_eq!(format!("{:+}", BigInt::from(42_i64)), "+42");
+        assert_eq!(format!("{:>5}", BigInt::from(-42_i64)), "  -42");
+        assert_eq!("-123".parse(), Ok(BigInt::from(-123_i64)));
+        assert_eq!("+123".parse(), Ok(BigInt::from(123_i64)));
+        assert_eq!("-0".parse(), Ok(BigInt::from(0_i64)));
+        assert_eq!("-".parse::<BigInt>(), Err(ParseBigUIntError::Empty));
+    }
+}
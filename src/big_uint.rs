@@ -1,64 +1,702 @@
 use std::{
-    fmt::Debug,
+    cmp::Ordering,
+    fmt::{Binary, Debug, Display, LowerHex},
     iter::Sum,
-    ops::{Add, AddAssign, Mul},
+    ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign},
+    str::FromStr,
 };
 
-#[derive(Clone, PartialEq, PartialOrd, Eq, Ord)]
-pub struct BigUInt(Vec<u8>);
+/// Base of each limb of a `BigUInt`.
+const LIMB_BASE: u64 = 1_000_000_000;
+/// Number of decimal digits stored on each limb.
+const LIMB_DIGITS: usize = 9;
+/// Minimum number of limbs on both operands for the multiplication to use Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Arbitrary-precision unsigned integer.
+///
+/// Stored as little-endian limbs in base `10^9`, without trailing zero limbs, so zero is represented
+/// by an empty list.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUInt(Vec<u32>);
 
 impl BigUInt {
+    /// Builds a `BigUInt` from a list of limbs, removing the most significant zero limbs.
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self(limbs)
+    }
+
+    /// Builds a `BigUInt` from a list of big-endian decimal digits.
+    fn from_decimal_digits(digits: &[u8]) -> Self {
+        Self::from_limbs(
+            digits
+                .rchunks(LIMB_DIGITS)
+                .map(|chunk| chunk.iter().fold(0, |limb, d| limb * 10 + u32::from(*d)))
+                .collect(),
+        )
+    }
+
+    /// Get the big-endian decimal digits of the number.
+    fn decimal_digits(&self) -> Vec<u8> {
+        match self.0.split_last() {
+            None => vec![0],
+            Some((most_significant, rest)) => {
+                let mut digits = most_significant
+                    .to_string()
+                    .bytes()
+                    .map(|b| b - b'0')
+                    .collect::<Vec<_>>();
+                for limb in rest.iter().rev() {
+                    let mut limb = *limb;
+                    let mut chunk = [0; LIMB_DIGITS];
+                    for d in chunk.iter_mut().rev() {
+                        *d = (limb % 10) as u8;
+                        limb /= 10;
+                    }
+                    digits.extend(chunk);
+                }
+                digits
+            }
+        }
+    }
+
+    /// Get the little-endian base `2^32` words of the number.
+    fn binary_words(&self) -> Vec<u32> {
+        let mut words = vec![];
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem_u64(1 << 32);
+            words.push(remainder as u32);
+            rest = quotient;
+        }
+        words
+    }
+
+    /// Checks if the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn number_of_digits(&self) -> usize {
-        self.0.len()
+        match self.0.last() {
+            None => 1,
+            Some(most_significant) => {
+                (self.0.len() - 1) * LIMB_DIGITS + most_significant.ilog10() as usize + 1
+            }
+        }
+    }
+
+    /// Get the number of bits in the binary representation, `0` for zero.
+    pub fn bits(&self) -> u64 {
+        match self.binary_words().split_last() {
+            None => 0,
+            Some((most_significant, rest)) => {
+                rest.len() as u64 * 32 + u64::from(32 - most_significant.leading_zeros())
+            }
+        }
     }
 
+    /// Iterate over the decimal digits of the square root, starting from the most significant
+    /// digit of the integer part and continuing forever into the fractional part.
+    ///
+    /// Uses the digit-by-digit method, so every digit is exact.
+    ///
+    /// ```
+    /// use project_euler::BigUInt;
+    /// let digits = BigUInt::from(2_u64).sqrt_digits().take(10).collect::<Vec<_>>();
+    /// assert_eq!(digits, vec![1, 4, 1, 4, 2, 1, 3, 5, 6, 2]);
+    /// ```
+    pub fn sqrt_digits(&self) -> SqrtDigits {
+        let mut digits = self.decimal_digits();
+        if digits.len() % 2 == 1 {
+            digits.insert(0, 0);
+        }
+        SqrtDigits {
+            pairs: digits
+                .chunks(2)
+                .map(|pair| pair[0] * 10 + pair[1])
+                .collect(),
+            index: 0,
+            remainder: BigUInt::default(),
+            root: BigUInt::default(),
+        }
+    }
+
+    /// Raises the number to the power of `n` using exponentiation by squaring.
     pub fn pow(self, n: u64) -> Self {
-        (0..n).fold(BigUInt::from(1), |sum, _| self.clone() * sum)
+        let mut base = self;
+        let mut exp = n;
+        let mut res = BigUInt::from(1_u64);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res * base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        res
+    }
+
+    /// Raises the number to the power of `n`, returning `None` if the result would have more than
+    /// `max_digits` decimal digits.
+    pub fn checked_pow(self, n: u64, max_digits: usize) -> Option<Self> {
+        let mut base = self;
+        let mut exp = n;
+        let mut res = BigUInt::from(1_u64);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res * base.clone();
+                if res.number_of_digits() > max_digits {
+                    return None;
+                }
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+                if base.number_of_digits() > max_digits {
+                    return None;
+                }
+            }
+        }
+        (res.number_of_digits() <= max_digits).then_some(res)
+    }
+
+    /// Calculates `self` raised to the power of `exp` modulo `modulus`.
+    ///
+    /// # Panic
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod<E: Into<BigUInt>>(&self, exp: E, modulus: &BigUInt) -> Self {
+        if modulus.is_zero() {
+            panic!("Zero division.")
+        }
+        let mut base = self.clone() % modulus;
+        let mut res = BigUInt::from(1_u64) % modulus;
+        let words = exp.into().binary_words();
+        for (i, word) in words.iter().enumerate() {
+            for bit in 0..32 {
+                if (word >> bit) & 1 == 1 {
+                    res = (res * base.clone()) % modulus;
+                }
+                if i + 1 < words.len() || (word >> bit) > 1 {
+                    base = (base.clone() * base) % modulus;
+                }
+            }
+        }
+        res
     }
 
     pub fn sum_of_digits(&self) -> u64 {
-        self.0.iter().cloned().map(u64::from).sum()
+        self.0
+            .iter()
+            .map(|limb| {
+                let mut limb = *limb;
+                let mut sum = 0;
+                while limb > 0 {
+                    sum += u64::from(limb % 10);
+                    limb /= 10;
+                }
+                sum
+            })
+            .sum()
     }
 
     pub fn reverse(self) -> Self {
-        let mut rev: Vec<_> = self.0.iter().cloned().rev().collect();
-        while rev.first() == Some(&0) {
-            rev.remove(0);
-        }
-        Self(rev)
+        let mut digits = self.decimal_digits();
+        digits.reverse();
+        Self::from_decimal_digits(&digits)
     }
 
     pub fn is_palindrome(&self) -> bool {
-        self.0.iter().rev().eq(self.0.iter())
+        let digits = self.decimal_digits();
+        digits.iter().rev().eq(digits.iter())
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` if `rhs` is greater than `self`.
+    pub fn checked_sub(&self, rhs: &BigUInt) -> Option<Self> {
+        if self < rhs {
+            None
+        } else {
+            Some(Self::from_limbs(sub_limbs(&self.0, &rhs.0)))
+        }
+    }
+
+    /// Calculates the quotient and remainder of the division of `self` by `rhs`.
+    ///
+    /// # Panic
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &BigUInt) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("Zero division.")
+        } else if let Ok(small) = u64::try_from(rhs) {
+            let (quotient, remainder) = self.div_rem_u64(small);
+            return (quotient, Self::from(remainder));
+        } else if self < rhs {
+            return (Self::default(), self.clone());
+        }
+
+        // The divisor does not fit in a `u64`, so it has at least 3 limbs.
+        let divisor_top = u128::from(rhs.0[rhs.0.len() - 1]) * u128::from(LIMB_BASE)
+            + u128::from(rhs.0[rhs.0.len() - 2]);
+        let mut quotient = vec![0; self.0.len()];
+        let mut remainder = Self::default();
+        for (i, limb) in self.0.iter().enumerate().rev() {
+            remainder.0.insert(0, *limb);
+            remainder = Self::from_limbs(remainder.0);
+            if remainder < *rhs {
+                continue;
+            }
+
+            // Estimate the quotient limb from the most significant limbs, then correct it.
+            let top = (rhs.0.len() - 2..=rhs.0.len())
+                .rev()
+                .map(|j| u128::from(*remainder.0.get(j).unwrap_or(&0)))
+                .fold(0, |acc, l| acc * u128::from(LIMB_BASE) + l);
+            let mut q = (top / divisor_top).min(u128::from(LIMB_BASE - 1)) as u32;
+            let mut product = Self::from_limbs(mul_limb(&rhs.0, q));
+            while product > remainder {
+                q -= 1;
+                product = Self::from_limbs(sub_limbs(&product.0, &rhs.0));
+            }
+            remainder = Self::from_limbs(sub_limbs(&remainder.0, &product.0));
+            while remainder >= *rhs {
+                q += 1;
+                remainder = Self::from_limbs(sub_limbs(&remainder.0, &rhs.0));
+            }
+            quotient[i] = q;
+        }
+        (Self::from_limbs(quotient), remainder)
+    }
+
+    /// Calculates the quotient and remainder of the division of `self` by a small integer `rhs`.
+    ///
+    /// # Panic
+    /// Panics if `rhs` is zero.
+    pub fn div_rem_u64(&self, rhs: u64) -> (Self, u64) {
+        if rhs == 0 {
+            panic!("Zero division.")
+        }
+        let rhs = u128::from(rhs);
+        let mut quotient = vec![0; self.0.len()];
+        let mut remainder = 0_u128;
+        for (i, limb) in self.0.iter().enumerate().rev() {
+            let cur = remainder * u128::from(LIMB_BASE) + u128::from(*limb);
+            quotient[i] = (cur / rhs) as u32;
+            remainder = cur % rhs;
+        }
+        (Self::from_limbs(quotient), remainder as u64)
+    }
+
+    /// Strong probable-prime test of the number to `base`.
+    ///
+    /// Every prime passes the test, a composite that passes is a strong pseudoprime to `base`.
+    pub fn is_strong_probable_prime(&self, base: &BigUInt) -> bool {
+        let one = BigUInt::from(1_u64);
+        let two = BigUInt::from(2_u64);
+        if *self < two {
+            return false;
+        } else if *self == two {
+            return true;
+        } else if self.clone() % 2 == 0 {
+            return false;
+        }
+
+        let minus_one = self.clone() - &one;
+        let mut d = minus_one.clone();
+        let mut s = 0;
+        while d.clone() % 2 == 0 {
+            d = d / 2;
+            s += 1;
+        }
+
+        let base = base.clone() % self;
+        if base.is_zero() || base == one || base == minus_one {
+            return true;
+        }
+        let mut x = base.pow_mod(&d, self);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = (x.clone() * x) % self;
+            if x == minus_one {
+                return true;
+            } else if x == one {
+                return false;
+            }
+        }
+        false
+    }
+
+    /// Checks if the number is probably prime, by testing it against the first 13 primes as
+    /// strong probable-prime bases.
+    ///
+    /// The answer is exact for numbers below `3.3 * 10^24`.
+    pub fn is_probable_prime(&self) -> bool {
+        const BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+        if let Ok(small) = u64::try_from(self) {
+            return crate::is_prime(small);
+        }
+        BASES
+            .into_iter()
+            .all(|b| self.is_strong_probable_prime(&BigUInt::from(b)))
+    }
+}
+
+/// Adds two lists of limbs.
+fn add_limbs(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    let mut carry = 0;
+    let mut out = long
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let add = u64::from(*l) + u64::from(*short.get(i).unwrap_or(&0)) + carry;
+            carry = add / LIMB_BASE;
+            (add % LIMB_BASE) as u32
+        })
+        .collect::<Vec<_>>();
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// Subtracts two lists of limbs, `lhs` must be greater or equal to `rhs`.
+fn sub_limbs(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut borrow = 0;
+    lhs.iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let sub = u64::from(*rhs.get(i).unwrap_or(&0)) + borrow;
+            let l = u64::from(*l);
+            if l >= sub {
+                borrow = 0;
+                (l - sub) as u32
+            } else {
+                borrow = 1;
+                (l + LIMB_BASE - sub) as u32
+            }
+        })
+        .collect()
+}
+
+/// Multiplies a list of limbs by a single limb.
+fn mul_limb(lhs: &[u32], rhs: u32) -> Vec<u32> {
+    let mut carry = 0;
+    let mut out = lhs
+        .iter()
+        .map(|l| {
+            let mul = u64::from(*l) * u64::from(rhs) + carry;
+            carry = mul / LIMB_BASE;
+            (mul % LIMB_BASE) as u32
+        })
+        .collect::<Vec<_>>();
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// Multiplies two lists of limbs using the schoolbook method.
+fn schoolbook_mul(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut out = vec![0_u32; lhs.len() + rhs.len()];
+    for (i, l) in lhs.iter().enumerate() {
+        let mut carry = 0;
+        for (j, r) in rhs.iter().enumerate() {
+            let mul = u64::from(out[i + j]) + u64::from(*l) * u64::from(*r) + carry;
+            out[i + j] = (mul % LIMB_BASE) as u32;
+            carry = mul / LIMB_BASE;
+        }
+        out[i + rhs.len()] = carry as u32;
+    }
+    out
+}
+
+/// Multiplies two lists of limbs using Karatsuba's method, falling back to the schoolbook method
+/// for small operands.
+fn mul_limbs(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    if lhs.len().min(rhs.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook_mul(lhs, rhs);
+    }
+
+    let half = lhs.len().max(rhs.len()) / 2;
+    let (l0, l1) = lhs.split_at(half.min(lhs.len()));
+    let (r0, r1) = rhs.split_at(half.min(rhs.len()));
+
+    let z0 = mul_limbs(l0, r0);
+    let z2 = mul_limbs(l1, r1);
+    let z1 = BigUInt::from_limbs(sub_limbs(
+        &sub_limbs(&mul_limbs(&add_limbs(l0, l1), &add_limbs(r0, r1)), &z0),
+        &z2,
+    ))
+    .0;
+
+    let mut out = vec![0_u32; lhs.len() + rhs.len() + 1];
+    for (shift, part) in [(0, z0), (half, z1), (2 * half, z2)] {
+        let mut carry = 0;
+        let mut i = shift;
+        for limb in part {
+            let add = u64::from(out[i]) + u64::from(limb) + carry;
+            out[i] = (add % LIMB_BASE) as u32;
+            carry = add / LIMB_BASE;
+            i += 1;
+        }
+        while carry > 0 {
+            let add = u64::from(out[i]) + carry;
+            out[i] = (add % LIMB_BASE) as u32;
+            carry = add / LIMB_BASE;
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Iterator over the decimal digits of the square root of a [`BigUInt`].
+///
+/// Created by [`BigUInt::sqrt_digits`].
+pub struct SqrtDigits {
+    pairs: Vec<u8>,
+    index: usize,
+    remainder: BigUInt,
+    root: BigUInt,
+}
+
+impl Iterator for SqrtDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pair = self.pairs.get(self.index).copied().unwrap_or(0);
+        self.index += 1;
+
+        let current = std::mem::take(&mut self.remainder) * BigUInt::from(100_u64)
+            + BigUInt::from(u64::from(pair));
+        let base = std::mem::take(&mut self.root) * BigUInt::from(20_u64);
+        // Largest digit `x` where `(20 * root + x) * x` fits in the current remainder.
+        let (digit, used) = (0..=9_u64)
+            .rev()
+            .map(|x| (x, (base.clone() + BigUInt::from(x)) * BigUInt::from(x)))
+            .find(|(_, used)| *used <= current)
+            .unwrap_or_default();
+        self.remainder = current - used;
+        self.root = base / 2 + BigUInt::from(digit);
+        Some(digit as u8)
+    }
+}
+
+impl PartialOrd for BigUInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+/// Error returned when parsing a `BigUInt` from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigUIntError {
+    /// The string had no digits.
+    Empty,
+    /// The string started with a `+` or `-` sign.
+    Sign(char),
+    /// The string contained a character that is not a decimal digit or a `_` separator.
+    InvalidDigit(char),
+}
+
+impl Display for ParseBigUIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Cannot parse a BigUInt from an empty string."),
+            Self::Sign(c) => write!(f, "A BigUInt cannot have a sign, found '{c}'."),
+            Self::InvalidDigit(c) => write!(f, "Invalid digit '{c}' found in string."),
+        }
     }
 }
 
-impl Default for BigUInt {
-    fn default() -> Self {
-        Self(vec![0])
+impl std::error::Error for ParseBigUIntError {}
+
+/// Error returned when a `BigUInt` or a `BigInt` does not fit in the type it is converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBigIntError;
+
+impl Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The number does not fit in the target type.")
+    }
+}
+
+impl std::error::Error for TryFromBigIntError {}
+
+impl FromStr for BigUInt {
+    type Err = ParseBigUIntError;
+
+    /// Parses a decimal number, allowing single `_` separators between digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.chars().next() {
+            None => return Err(ParseBigUIntError::Empty),
+            Some(c @ ('+' | '-')) => return Err(ParseBigUIntError::Sign(c)),
+            Some(_) => (),
+        }
+        if s.starts_with('_') || s.ends_with('_') || s.contains("__") {
+            return Err(ParseBigUIntError::InvalidDigit('_'));
+        }
+        let digits = s
+            .chars()
+            .filter(|c| *c != '_')
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|d| d as u8)
+                    .ok_or(ParseBigUIntError::InvalidDigit(c))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_decimal_digits(&digits))
+    }
+}
+
+impl Display for BigUInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = match self.0.split_last() {
+            None => "0".to_owned(),
+            Some((most_significant, rest)) => {
+                rest.iter()
+                    .rev()
+                    .fold(most_significant.to_string(), |mut digits, limb| {
+                        digits.push_str(&format!("{limb:09}"));
+                        digits
+                    })
+            }
+        };
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl LowerHex for BigUInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let words = self.binary_words();
+        let digits = match words.split_last() {
+            None => "0".to_owned(),
+            Some((most_significant, rest)) => {
+                rest.iter()
+                    .rev()
+                    .fold(format!("{most_significant:x}"), |mut digits, word| {
+                        digits.push_str(&format!("{word:08x}"));
+                        digits
+                    })
+            }
+        };
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+impl Binary for BigUInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let words = self.binary_words();
+        let digits = match words.split_last() {
+            None => "0".to_owned(),
+            Some((most_significant, rest)) => {
+                rest.iter()
+                    .rev()
+                    .fold(format!("{most_significant:b}"), |mut digits, word| {
+                        digits.push_str(&format!("{word:032b}"));
+                        digits
+                    })
+            }
+        };
+        f.pad_integral(true, "0b", &digits)
     }
 }
 
 impl Debug for BigUInt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.decimal_digits();
         writeln!(
             f,
             "[{};{}]",
-            self.0.iter().map(|d| d.to_string()).collect::<String>(),
-            self.0.len()
+            digits.iter().map(|d| d.to_string()).collect::<String>(),
+            digits.len()
         )
     }
 }
 
 impl From<&str> for BigUInt {
+    /// Parses a decimal number.
+    ///
+    /// # Panic
+    /// Panics if the string is not a valid number, use [`str::parse`] for a fallible conversion.
     fn from(s: &str) -> Self {
-        Self(s.chars().map(|c| c.to_digit(10).unwrap() as u8).collect())
+        match s.parse() {
+            Ok(n) => n,
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+impl From<&BigUInt> for BigUInt {
+    fn from(n: &BigUInt) -> Self {
+        n.clone()
     }
 }
 
 impl From<u64> for BigUInt {
     fn from(n: u64) -> Self {
-        n.to_string().as_str().into()
+        Self::from_limbs(vec![
+            (n % LIMB_BASE) as u32,
+            (n / LIMB_BASE % LIMB_BASE) as u32,
+            (n / LIMB_BASE / LIMB_BASE) as u32,
+        ])
+    }
+}
+
+impl From<u128> for BigUInt {
+    fn from(mut n: u128) -> Self {
+        let mut limbs = vec![];
+        while n > 0 {
+            limbs.push((n % u128::from(LIMB_BASE)) as u32);
+            n /= u128::from(LIMB_BASE);
+        }
+        Self(limbs)
+    }
+}
+
+impl TryFrom<&BigUInt> for u128 {
+    type Error = TryFromBigIntError;
+
+    fn try_from(value: &BigUInt) -> Result<Self, Self::Error> {
+        value
+            .0
+            .iter()
+            .rev()
+            .try_fold(0_u128, |acc, limb| {
+                acc.checked_mul(u128::from(LIMB_BASE))?
+                    .checked_add(u128::from(*limb))
+            })
+            .ok_or(TryFromBigIntError)
+    }
+}
+
+impl TryFrom<&BigUInt> for u64 {
+    type Error = TryFromBigIntError;
+
+    fn try_from(value: &BigUInt) -> Result<Self, Self::Error> {
+        u128::try_from(value)
+            .ok()
+            .and_then(|n| u64::try_from(n).ok())
+            .ok_or(TryFromBigIntError)
     }
 }
 
@@ -66,37 +704,7 @@ impl Add<&BigUInt> for BigUInt {
     type Output = Self;
 
     fn add(self, rhs: &BigUInt) -> Self::Output {
-        let mut l = self.0.iter().rev();
-        let mut r = rhs.0.iter().rev();
-
-        let mut carry = 0;
-        let mut out = vec![];
-        loop {
-            match (l.next(), r.next()) {
-                (Some(lv), Some(rv)) => {
-                    let add = lv + rv + carry;
-                    carry = add / 10;
-                    out.push(add % 10);
-                }
-                (Some(lv), None) => {
-                    let add = lv + carry;
-                    carry = add / 10;
-                    out.push(add % 10);
-                }
-                (None, Some(rv)) => {
-                    let add = rv + carry;
-                    carry = add / 10;
-                    out.push(add % 10);
-                }
-                (None, None) => {
-                    if carry > 0 {
-                        out.push(carry);
-                    }
-                    break;
-                }
-            }
-        }
-        Self(out.into_iter().rev().collect())
+        Self(add_limbs(&self.0, &rhs.0))
     }
 }
 
@@ -118,13 +726,77 @@ impl Mul<BigUInt> for BigUInt {
     type Output = Self;
 
     fn mul(self, rhs: BigUInt) -> Self::Output {
-        let mult = rhs.0.iter().rev().scan(0, |tens, cur| {
-            let mut new: Vec<_> = self.0.iter().rev().map(|d| d * cur).rev().collect();
-            new.extend(vec![0; *tens as usize]);
-            *tens += 1;
-            Some(new)
-        });
-        mult.fold(BigUInt::default(), |a, b| a + Self(b))
+        Self::from_limbs(mul_limbs(&self.0, &rhs.0))
+    }
+}
+
+impl Sub<&BigUInt> for BigUInt {
+    type Output = Self;
+
+    fn sub(self, rhs: &BigUInt) -> Self::Output {
+        self.checked_sub(rhs).expect("Subtraction overflow.")
+    }
+}
+
+impl Sub<BigUInt> for BigUInt {
+    type Output = Self;
+
+    fn sub(self, rhs: BigUInt) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl SubAssign<BigUInt> for BigUInt {
+    fn sub_assign(&mut self, rhs: BigUInt) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl Div<&BigUInt> for BigUInt {
+    type Output = Self;
+
+    fn div(self, rhs: &BigUInt) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Div<BigUInt> for BigUInt {
+    type Output = Self;
+
+    fn div(self, rhs: BigUInt) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl Div<u64> for BigUInt {
+    type Output = Self;
+
+    fn div(self, rhs: u64) -> Self::Output {
+        self.div_rem_u64(rhs).0
+    }
+}
+
+impl Rem<&BigUInt> for BigUInt {
+    type Output = Self;
+
+    fn rem(self, rhs: &BigUInt) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Rem<BigUInt> for BigUInt {
+    type Output = Self;
+
+    fn rem(self, rhs: BigUInt) -> Self::Output {
+        self % &rhs
+    }
+}
+
+impl Rem<u64> for BigUInt {
+    type Output = u64;
+
+    fn rem(self, rhs: u64) -> Self::Output {
+        self.div_rem_u64(rhs).1
     }
 }
 
@@ -147,4 +819,260 @@ mod test {
         assert!(BigUInt::from("90109").is_palindrome());
         assert!(!BigUInt::from("91109").is_palindrome());
     }
+
+    #[test]
+    fn ord_test() {
+        assert!(BigUInt::from(9_u64) < BigUInt::from(10_u64));
+        assert!(BigUInt::from(100_u64) > BigUInt::from(99_u64));
+        assert_eq!(
+            BigUInt::from(12_u64) * BigUInt::from(0_u64),
+            BigUInt::from(0_u64)
+        );
+    }
+
+    #[test]
+    fn sub_test() {
+        assert_eq!(
+            BigUInt::from(10_u64) - BigUInt::from(1_u64),
+            BigUInt::from(9_u64)
+        );
+        assert_eq!(
+            BigUInt::from(1000_u64) - BigUInt::from(999_u64),
+            BigUInt::from(1_u64)
+        );
+        assert_eq!(
+            BigUInt::from(42_u64) - BigUInt::from(42_u64),
+            BigUInt::from(0_u64)
+        );
+        assert_eq!(
+            BigUInt::from(3_u64).checked_sub(&BigUInt::from(4_u64)),
+            None
+        );
+        let mut n = BigUInt::from(12345_u64);
+        n -= BigUInt::from(2345_u64);
+        assert_eq!(n, BigUInt::from(10000_u64));
+    }
+
+    #[test]
+    fn div_rem_test() {
+        assert_eq!(
+            BigUInt::from(100_u64).div_rem(&BigUInt::from(7_u64)),
+            (BigUInt::from(14_u64), BigUInt::from(2_u64))
+        );
+        assert_eq!(
+            BigUInt::from(5_u64).div_rem(&BigUInt::from(7_u64)),
+            (BigUInt::from(0_u64), BigUInt::from(5_u64))
+        );
+        assert_eq!(
+            BigUInt::from("123456789012345678901234567890") / BigUInt::from(1234567890_u64),
+            BigUInt::from("100000000010000000001")
+        );
+        assert_eq!(
+            BigUInt::from("123456789012345678901234567890") % BigUInt::from(1000000007_u64),
+            BigUInt::from((123456789012345678901234567890_u128 % 1000000007) as u64)
+        );
+        assert_eq!(
+            BigUInt::from(100_u64).div_rem_u64(7),
+            (BigUInt::from(14_u64), 2)
+        );
+        assert_eq!(BigUInt::from(u64::MAX) % u64::MAX, 0);
+        assert_eq!(BigUInt::from(u64::MAX) / 2, BigUInt::from(u64::MAX / 2));
+    }
+
+    #[test]
+    fn karatsuba_test() {
+        let a = (1..400_u64)
+            .map(BigUInt::from)
+            .fold(BigUInt::from(1_u64), |a, b| a * b);
+        let b = (400..700_u64)
+            .map(BigUInt::from)
+            .fold(BigUInt::from(1_u64), |a, b| a * b);
+        assert!(a.0.len() >= KARATSUBA_THRESHOLD && b.0.len() >= KARATSUBA_THRESHOLD);
+        let product = a.clone() * b.clone();
+        assert_eq!(product, BigUInt::from_limbs(schoolbook_mul(&a.0, &b.0)));
+        assert_eq!(product.div_rem(&b), (a, BigUInt::default()));
+    }
+
+    #[test]
+    fn bits_test() {
+        assert_eq!(BigUInt::from(0_u64).bits(), 0);
+        assert_eq!(BigUInt::from(1_u64).bits(), 1);
+        assert_eq!(BigUInt::from(u64::MAX).bits(), 64);
+        assert_eq!(BigUInt::from(2_u64).pow(1000).bits(), 1001);
+    }
+
+    #[test]
+    fn sqrt_digits_test() {
+        assert_eq!(
+            BigUInt::from(2_u64)
+                .sqrt_digits()
+                .take(100)
+                .map(u64::from)
+                .sum::<u64>(),
+            475
+        );
+        assert_eq!(
+            BigUInt::from(15241383936_u64)
+                .sqrt_digits()
+                .take(8)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6, 0, 0]
+        );
+        assert_eq!(
+            BigUInt::from(0_u64)
+                .sqrt_digits()
+                .take(3)
+                .collect::<Vec<_>>(),
+            vec![0, 0, 0]
+        );
+        let root = BigUInt::from("123456789012345678901234567890")
+            .sqrt_digits()
+            .take(15)
+            .fold(BigUInt::default(), |n, d| {
+                n * BigUInt::from(10_u64) + BigUInt::from(u64::from(d))
+            });
+        assert_eq!(root, BigUInt::from(351364182882014_u64));
+    }
+
+    #[test]
+    fn decimal_digits_test() {
+        assert_eq!(BigUInt::from(0_u64).number_of_digits(), 1);
+        assert_eq!(BigUInt::from(1_000_000_000_u64).number_of_digits(), 10);
+        assert_eq!(BigUInt::from(u64::MAX).sum_of_digits(), 87);
+        assert_eq!(
+            BigUInt::from("1000000000000000000002").reverse(),
+            BigUInt::from("2000000000000000000001")
+        );
+        assert_eq!(BigUInt::from(1200_u64).reverse(), BigUInt::from(21_u64));
+        assert!(BigUInt::from("1234567890987654321").is_palindrome());
+    }
+
+    #[test]
+    fn conversion_test() {
+        assert_eq!(u128::try_from(&BigUInt::from(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(u64::try_from(&BigUInt::from(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(
+            u64::try_from(&BigUInt::from(u128::from(u64::MAX) + 1)),
+            Err(TryFromBigIntError)
+        );
+        assert!(u128::try_from(&(BigUInt::from(u128::MAX) + BigUInt::from(1_u64))).is_err());
+        assert_eq!(u64::try_from(&BigUInt::default()), Ok(0));
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!("0".parse(), Ok(BigUInt::from(0_u64)));
+        assert_eq!("007".parse(), Ok(BigUInt::from(7_u64)));
+        assert_eq!("1_000_000".parse(), Ok(BigUInt::from(1_000_000_u64)));
+        assert_eq!("".parse::<BigUInt>(), Err(ParseBigUIntError::Empty));
+        assert_eq!("-1".parse::<BigUInt>(), Err(ParseBigUIntError::Sign('-')));
+        assert_eq!("+1".parse::<BigUInt>(), Err(ParseBigUIntError::Sign('+')));
+        for s in ["_1", "10_", "1__0", "_"] {
+            assert_eq!(
+                s.parse::<BigUInt>(),
+                Err(ParseBigUIntError::InvalidDigit('_'))
+            );
+        }
+        assert_eq!(
+            "12a4".parse::<BigUInt>(),
+            Err(ParseBigUIntError::InvalidDigit('a'))
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        let n = BigUInt::from("123456789000000001");
+        assert_eq!(n.to_string(), "123456789000000001");
+        assert_eq!(BigUInt::from(0_u64).to_string(), "0");
+        assert_eq!(format!("{:>6}", BigUInt::from(42_u64)), "    42");
+        assert_eq!(format!("{:*<6}", BigUInt::from(42_u64)), "42****");
+        assert_eq!(format!("{:^6}", BigUInt::from(42_u64)), "  42  ");
+        assert_eq!(format!("{:06}", BigUInt::from(42_u64)), "000042");
+        assert_eq!(format!("{:x}", BigUInt::from(u64::MAX)), "ffffffffffffffff");
+        assert_eq!(format!("{:#x}", BigUInt::from(255_u64)), "0xff");
+        assert_eq!(format!("{:b}", BigUInt::from(10_u64)), "1010");
+        assert_eq!(format!("{:#b}", BigUInt::from(0_u64)), "0b0");
+        assert_eq!(
+            format!("{:x}", BigUInt::from(1_u64 << 32)),
+            format!("{:x}", 1_u64 << 32)
+        );
+    }
+
+    #[test]
+    fn pow_test() {
+        assert_eq!(BigUInt::from(7_u64).pow(0), BigUInt::from(1_u64));
+        assert_eq!(BigUInt::from(0_u64).pow(0), BigUInt::from(1_u64));
+        assert_eq!(BigUInt::from(3_u64).pow(40), BigUInt::from(3_u64.pow(40)));
+        assert_eq!(BigUInt::from(2_u64).pow(1000).sum_of_digits(), 1366);
+        assert_eq!(BigUInt::from(2_u64).pow(1000).number_of_digits(), 302);
+    }
+
+    #[test]
+    fn checked_pow_test() {
+        assert_eq!(
+            BigUInt::from(10_u64).checked_pow(5, 6),
+            Some(BigUInt::from(100_000_u64))
+        );
+        assert_eq!(BigUInt::from(10_u64).checked_pow(6, 6), None);
+        assert_eq!(
+            BigUInt::from(2_u64)
+                .checked_pow(1000, 302)
+                .map(|n| n.number_of_digits()),
+            Some(302)
+        );
+        assert_eq!(BigUInt::from(2_u64).checked_pow(1000, 301), None);
+    }
+
+    #[test]
+    fn pow_mod_test() {
+        let modulus = BigUInt::from(10_000_000_000_u64);
+        assert_eq!(
+            (1..=1000_u64)
+                .map(|n| BigUInt::from(n).pow_mod(n, &modulus))
+                .sum::<BigUInt>()
+                % &modulus,
+            BigUInt::from(9110846700_u64)
+        );
+        assert_eq!(
+            BigUInt::from(3_u64).pow_mod(
+                BigUInt::from(2_u64).pow(100),
+                &BigUInt::from(1_000_000_007_u64)
+            ),
+            BigUInt::from((0..100).fold(3_u128, |b, _| b * b % 1_000_000_007))
+        );
+        assert_eq!(
+            BigUInt::from(5_u64).pow_mod(0_u64, &BigUInt::from(1_u64)),
+            BigUInt::from(0_u64)
+        );
+    }
+
+    #[test]
+    fn probable_prime_test() {
+        assert!(BigUInt::from(2_u64).is_probable_prime());
+        assert!(!BigUInt::from(1_u64).is_probable_prime());
+        assert!(BigUInt::from(2_u64)
+            .pow(89)
+            .checked_sub(&BigUInt::from(1_u64))
+            .unwrap()
+            .is_probable_prime());
+        assert!(!BigUInt::from(2_u64)
+            .pow(83)
+            .checked_sub(&BigUInt::from(1_u64))
+            .unwrap()
+            .is_probable_prime());
+        // 3215031751 is a strong pseudoprime to bases 2, 3, 5 and 7.
+        let pseudoprime = BigUInt::from(3215031751_u64);
+        assert!(pseudoprime.is_strong_probable_prime(&BigUInt::from(2_u64)));
+        assert!(!pseudoprime.is_strong_probable_prime(&BigUInt::from(11_u64)));
+        assert!(
+            !(BigUInt::from(1_000_000_007_u64) * BigUInt::from(998_244_353_u64))
+                .is_probable_prime()
+        );
+        // The smallest strong pseudoprime to all of the first 12 prime bases, caught by 41.
+        let pseudoprime = "318665857834031151167461".parse::<BigUInt>().unwrap();
+        assert!([2_u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+            .into_iter()
+            .all(|b| pseudoprime.is_strong_probable_prime(&BigUInt::from(b))));
+        assert!(!pseudoprime.is_probable_prime());
+    }
 }
@@ -1,13 +1,13 @@
-use project_euler::smallest_multiple_of_all_through_x;
+use project_euler::lcm;
 
 fn main() {
-    let r = smallest_multiple_of_all_through_x(20);
+    let r = (1..=20_u64).fold(1, lcm);
     println!("{r:?}");
 }
 
 #[cfg(test)]
 mod test {
-    use project_euler::get_prime_factors_frequencies;
+    use project_euler::{get_prime_factors_frequencies, smallest_multiple_of_all_through_x};
 
     use super::*;
     use std::collections::HashMap;
@@ -15,6 +15,7 @@ mod test {
     #[test]
     fn multiple_of_all_test() {
         assert_eq!(smallest_multiple_of_all_through_x(10), 2520);
+        assert_eq!((1..=10_u64).fold(1, lcm), 2520);
     }
 
     #[test]
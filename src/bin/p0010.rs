@@ -1,12 +1,12 @@
-use project_euler::primes_up_to;
+use project_euler::prime_sum;
 
 fn main() {
-    println!("{}", primes_up_to(2_000_000).into_iter().sum::<u64>());
+    println!("{}", prime_sum(2_000_000));
 }
 
 #[cfg(test)]
 mod test {
-    use std::collections::BTreeSet;
+    use project_euler::primes_up_to;
 
     use super::*;
 
@@ -24,7 +24,7 @@ mod test {
 
     #[test]
     fn primes_up_to_test() {
-        assert_eq!(primes_up_to(1000), BTreeSet::from_iter(PRIMES_UP_TO_1000));
+        assert_eq!(primes_up_to(1000), PRIMES_UP_TO_1000.to_vec());
     }
 
     #[test]
@@ -32,5 +32,8 @@ mod test {
         assert_eq!(primes_up_to(10).into_iter().sum::<u64>(), 17);
         assert_eq!(primes_up_to(11).into_iter().sum::<u64>(), 28);
         assert_eq!(primes_up_to(15).into_iter().sum::<u64>(), 41);
+        assert_eq!(prime_sum(10), 17);
+        assert_eq!(prime_sum(11), 28);
+        assert_eq!(prime_sum(15), 41);
     }
 }
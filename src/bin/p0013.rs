@@ -3,7 +3,7 @@ use project_euler::BigUInt;
 fn main() {
     let nums: Vec<BigUInt> = LONG_NUMBER.iter().map(|ln| (*ln).into()).collect();
     let r = nums.iter().fold(BigUInt::default(), |a, b| a + b.clone());
-    println!("{:?}", r);
+    println!("{r}");
 }
 
 const LONG_NUMBER: [&str; 100] = [
@@ -1,7 +1,7 @@
 use project_euler::BigUInt;
 
 fn main() {
-    println!("{}", BigUInt::from(2).pow(1000).sum_of_digits());
+    println!("{}", BigUInt::from(2_u64).pow(1000).sum_of_digits());
 }
 
 #[cfg(test)]
@@ -10,14 +10,26 @@ mod test {
 
     #[test]
     fn mul_digit_lists_test() {
-        assert_eq!(BigUInt::from(2) * BigUInt::from(2), BigUInt::from(4));
-        assert_eq!(BigUInt::from(2) * BigUInt::from(8), BigUInt::from(16));
-        assert_eq!(BigUInt::from(5) * BigUInt::from(5), BigUInt::from(25));
-        assert_eq!(BigUInt::from(50) * BigUInt::from(2), BigUInt::from(100));
+        assert_eq!(
+            BigUInt::from(2_u64) * BigUInt::from(2_u64),
+            BigUInt::from(4_u64)
+        );
+        assert_eq!(
+            BigUInt::from(2_u64) * BigUInt::from(8_u64),
+            BigUInt::from(16_u64)
+        );
+        assert_eq!(
+            BigUInt::from(5_u64) * BigUInt::from(5_u64),
+            BigUInt::from(25_u64)
+        );
+        assert_eq!(
+            BigUInt::from(50_u64) * BigUInt::from(2_u64),
+            BigUInt::from(100_u64)
+        );
     }
 
     #[test]
     fn power_sum_test() {
-        assert_eq!(BigUInt::from(2).pow(15).sum_of_digits(), 26);
+        assert_eq!(BigUInt::from(2_u64).pow(15).sum_of_digits(), 26);
     }
 }
@@ -3,7 +3,7 @@ use project_euler::BigUInt;
 fn main() {
     println!(
         "{}",
-        (1..=100)
+        (1..=100_u64)
             .map(BigUInt::from)
             .reduce(|a, b| a * b)
             .unwrap()
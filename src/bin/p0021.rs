@@ -1,15 +1,24 @@
-use project_euler::is_amicable_number;
+use project_euler::MultiplicativeSieve;
+
+const LIMIT: u64 = 10_000;
 
 fn main() {
+    // The aliquot sum of a number below the limit may go over it, so the tables go further.
+    let sieve = MultiplicativeSieve::new(4 * LIMIT);
     println!(
         "{}",
-        (0..10_000).filter(|n| is_amicable_number(*n)).sum::<u64>()
+        (1..LIMIT)
+            .filter(|a| {
+                let b = sieve.aliquot_sum(*a);
+                b != *a && b <= sieve.limit() && sieve.aliquot_sum(b) == *a
+            })
+            .sum::<u64>()
     );
 }
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use project_euler::is_amicable_number;
 
     #[test]
     fn is_amicable_number_test() {
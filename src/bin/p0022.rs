@@ -13,17 +13,7 @@ fn main() {
     );
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn uppercase_ascii_string_score_test() {
-        assert_eq!(uppercase_ascii_string_score("COLIN"), 53);
-    }
-}
-
-const NAMES: [&str; 5163] = [
+static NAMES: [&str; 5163] = [
     "MARY",
     "PATRICIA",
     "LINDA",
@@ -5188,3 +5178,13 @@ const NAMES: [&str; 5163] = [
     "BRODERICK",
     "ALONSO",
 ];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn uppercase_ascii_string_score_test() {
+        assert_eq!(uppercase_ascii_string_score("COLIN"), 53);
+    }
+}
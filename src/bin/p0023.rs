@@ -5,13 +5,10 @@ use project_euler::NumberFactorSumClass;
 const ABUNDANT_SUM_LIMIT: u64 = 28123;
 
 fn main() {
-    let abundant_numbers: Vec<_> = (1..=ABUNDANT_SUM_LIMIT)
-        .filter(|n| {
-            matches!(
-                NumberFactorSumClass::get_class(n),
-                NumberFactorSumClass::Abundant
-            )
-        })
+    let abundant_numbers: Vec<_> = NumberFactorSumClass::classify_up_to(ABUNDANT_SUM_LIMIT)
+        .into_iter()
+        .filter(|(_, class)| matches!(class, NumberFactorSumClass::Abundant))
+        .map(|(n, _)| n)
         .collect();
     let sums_of_abundants: BTreeSet<u64> = abundant_numbers
         .iter()
@@ -38,6 +35,10 @@ mod test {
             NumberFactorSumClass::get_class(&12),
             NumberFactorSumClass::Abundant
         ));
+        assert_eq!(
+            NumberFactorSumClass::get_class(&0),
+            NumberFactorSumClass::Abundant
+        );
         for n in 1..12 {
             assert!(matches!(
                 NumberFactorSumClass::get_class(&n),
@@ -5,7 +5,7 @@ fn main() {
         "{:?}",
         (0..1)
             .cycle()
-            .scan((BigUInt::from(1), BigUInt::from(1)), |prev, _| {
+            .scan((BigUInt::from(1_u64), BigUInt::from(1_u64)), |prev, _| {
                 let next = prev.0.clone() + prev.1.clone();
                 *prev = (prev.1.clone(), next.clone());
                 Some(next)
@@ -4,11 +4,7 @@ fn main() {
     println!(
         "{:?}",
         (1..=1000)
-            .enumerate()
-            .map(|(i, d)| (
-                i + 1,
-                UnitFraction::new(d, 2_usize.pow(12)).get_cycle_length()
-            ))
+            .map(|d| (d, UnitFraction::from(d).period()))
             .max_by(|(_, l), (_, r)| l.cmp(r))
     );
 }
@@ -1,10 +1,13 @@
-use project_euler::QuadraticFormula;
+use project_euler::{Primes, QuadraticFormula};
 
 fn main() {
+    // `b` is the value of the formula for `n = 0`, so it must be prime.
+    let primes = Primes::new().take_while(|b| *b <= 1000).collect::<Vec<_>>();
     let r = (-1000..1000)
         .flat_map(|a| {
-            (0..=1000)
-                .map(|b| QuadraticFormula::new(a, b))
+            primes
+                .iter()
+                .map(|b| QuadraticFormula::new(a, *b as i64))
                 .collect::<Vec<_>>()
         })
         .map(|qf| {
@@ -6,7 +6,7 @@ impl Wallet {
     fn value(&self) -> u64 {
         self.0
             .iter()
-            .zip([200, 100, 50, 20, 10, 5, 2, 1].into_iter())
+            .zip([200, 100, 50, 20, 10, 5, 2, 1])
             .map(|(l, r)| *l as u64 * r)
             .sum()
     }
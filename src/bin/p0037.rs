@@ -1,12 +1,14 @@
-use project_euler::{primes_up_to, truncable_prime};
+use project_euler::{truncable_prime, Primes};
+
+/// There are only eleven primes that are truncable from both sides.
+const TRUNCABLE_PRIMES: usize = 11;
 
 fn main() {
-    let primes = primes_up_to(1_000_000);
     println!(
         "{:?}",
-        primes
-            .into_iter()
+        Primes::new()
             .filter(|x| truncable_prime(*x))
+            .take(TRUNCABLE_PRIMES)
             .sum::<u64>()
     )
 }
@@ -8,31 +8,7 @@ fn main() {
     println!("{r}");
 }
 
-#[cfg(test)]
-mod test {
-    use project_euler::nth_triangle_number;
-
-    use super::*;
-
-    #[test]
-    fn is_triangle_number_test() {
-        assert!(is_triangle_number(nth_triangle_number(1)));
-        assert!(is_triangle_number(nth_triangle_number(2)));
-        assert!(is_triangle_number(nth_triangle_number(3)));
-        assert!(is_triangle_number(nth_triangle_number(4)));
-        assert!(is_triangle_number(nth_triangle_number(5)));
-        assert!(is_triangle_number(nth_triangle_number(6)));
-        assert!(is_triangle_number(nth_triangle_number(7)));
-        assert!(is_triangle_number(nth_triangle_number(8)));
-        assert!(!is_triangle_number(2));
-        assert!(!is_triangle_number(5));
-        assert!(!is_triangle_number(7));
-        assert!(!is_triangle_number(8));
-        assert!(!is_triangle_number(9));
-    }
-}
-
-const WORD_LIST: [&str; 1786] = [
+static WORD_LIST: [&str; 1786] = [
     "A",
     "ABILITY",
     "ABLE",
@@ -1820,3 +1796,29 @@ const WORD_LIST: [&str; 1786] = [
     "YOURSELF",
     "YOUTH",
 ];
+
+#[cfg(test)]
+mod test {
+    use project_euler::nth_triangle_number;
+
+    use super::*;
+
+    #[test]
+    fn is_triangle_number_test() {
+        assert!(is_triangle_number(nth_triangle_number(1)));
+        assert!(is_triangle_number(nth_triangle_number(2)));
+        assert!(is_triangle_number(nth_triangle_number(3)));
+        assert!(is_triangle_number(nth_triangle_number(4)));
+        assert!(is_triangle_number(nth_triangle_number(5)));
+        assert!(is_triangle_number(nth_triangle_number(6)));
+        assert!(is_triangle_number(nth_triangle_number(7)));
+        assert!(is_triangle_number(nth_triangle_number(8)));
+        assert!(!is_triangle_number(2));
+        assert!(!is_triangle_number(5));
+        assert!(!is_triangle_number(7));
+        assert!(!is_triangle_number(8));
+        assert!(!is_triangle_number(9));
+        assert!(is_triangle_number(nth_triangle_number(4_000_000_000)));
+        assert!(!is_triangle_number(nth_triangle_number(4_000_000_000) + 1));
+    }
+}
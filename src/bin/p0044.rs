@@ -1,18 +1,23 @@
-use std::collections::BTreeSet;
-
-use project_euler::nth_pentagonal_number;
+use project_euler::{is_pentagonal, nth_pentagonal_number};
 
 fn main() {
-    let pentagon = (1..10_000)
-        .map(nth_pentagonal_number)
-        .collect::<BTreeSet<_>>();
-    let r = pentagon
-        .iter()
-        .flat_map(|l| pentagon.iter().zip(vec![l].into_iter().cycle()))
-        .filter(|(l, r)| l > r)
-        .filter(|(l, r)| pentagon.contains(&(*l + *r)) && pentagon.contains(&(*l - *r)))
-        .map(|(l, r)| l - r)
-        .min();
+    let mut r: Option<u64> = None;
+    for k in 2_u64.. {
+        let pk = nth_pentagonal_number(k);
+        // Every pair from here on differs by at least `P(k) - P(k - 1)`, so once that is past the
+        // best difference, the minimum is found.
+        if r.is_some_and(|d| pk - nth_pentagonal_number(k - 1) >= d) {
+            break;
+        }
+        let smaller = (1..k)
+            .rev()
+            .map(nth_pentagonal_number)
+            .take_while(|pj| r.is_none_or(|d| pk - pj < d))
+            .find(|pj| is_pentagonal(pk - pj) && is_pentagonal(pk + pj));
+        if let Some(pj) = smaller {
+            r = Some(pk - pj);
+        }
+    }
     println!("{r:?}");
 }
 
@@ -27,5 +32,11 @@ mod test {
         assert_eq!(nth_pentagonal_number(3), 12);
         assert_eq!(nth_pentagonal_number(4), 22);
         assert_eq!(nth_pentagonal_number(5), 35);
+        assert!(is_pentagonal(
+            nth_pentagonal_number(4) + nth_pentagonal_number(7)
+        ));
+        assert!(!is_pentagonal(
+            nth_pentagonal_number(7) - nth_pentagonal_number(4)
+        ));
     }
 }
@@ -20,14 +20,16 @@ mod test {
                 .map(|n| power_modulus(n, n, 10_u64.pow(10)))
                 .sum::<u64>(),
             405071317
-        )
+        );
+        assert_eq!(power_modulus(3, u64::MAX - 59, u64::MAX - 58), 1);
+        assert_eq!(power_modulus(10, 0, 1), 0);
     }
 
     #[test]
     fn self_powers_test() {
         assert_eq!(
             (1..=10).map(|n| BigUInt::from(n).pow(n)).sum::<BigUInt>(),
-            BigUInt::from(10405071317)
+            BigUInt::from(10405071317_u64)
         );
     }
 }
@@ -1,7 +1,10 @@
 use project_euler::Poker;
 
 fn main() {
-    println!("{:?}", Poker::resolve_tournament(GAMES));
+    println!(
+        "{:?}",
+        Poker::resolve_tournament(GAMES).map(|(player1, _, _)| player1)
+    );
 }
 
 const GAMES: &str = "8C TS KC 9H 4S 7D 2S 5D 3S AC
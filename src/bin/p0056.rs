@@ -1,11 +1,11 @@
 use project_euler::BigUInt;
 
 fn main() {
-    let r = (1..100)
+    let r = (1..100_u64)
         .map(|a| {
             let big_a = BigUInt::from(a);
             (1..100)
-                .scan(BigUInt::from(1), |prod, _| {
+                .scan(BigUInt::from(1_u64), |prod, _| {
                     *prod = prod.clone() * big_a.clone();
                     Some(prod.clone())
                 })
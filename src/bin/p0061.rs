@@ -1,80 +1,17 @@
 use std::{collections::BTreeSet, time::Instant};
 
-use project_euler::{
-    cyclic_number, nth_heptagonal_number, nth_hexagonal_number, nth_octagonal_number,
-    nth_pentagonal_number, nth_square_number, nth_triangle_number,
-};
+use project_euler::{cyclic_number, Figurate, Polygonal};
 
 fn main() {
     let s = Instant::now();
-    let triangle = (1..10000)
-        .filter_map(|n| {
-            let t = nth_triangle_number(n);
-            if (1000..10000).contains(&t) {
-                Some(t)
-            } else {
-                None
-            }
-        })
-        .collect::<BTreeSet<_>>();
-    let square = (1..10000)
-        .filter_map(|n| {
-            let t = nth_square_number(n);
-            if (1000..10000).contains(&t) {
-                Some(t)
-            } else {
-                None
-            }
-        })
-        .collect::<BTreeSet<_>>();
-    let pentagonal = (1..10000)
-        .filter_map(|n| {
-            let t = nth_pentagonal_number(n);
-            if (1000..10000).contains(&t) {
-                Some(t)
-            } else {
-                None
-            }
-        })
-        .collect::<BTreeSet<_>>();
-    let hexagonal = (1..10000)
-        .filter_map(|n| {
-            let t = nth_hexagonal_number(n);
-            if (1000..10000).contains(&t) {
-                Some(t)
-            } else {
-                None
-            }
-        })
-        .collect::<BTreeSet<_>>();
-    let heptagonal = (1..10000)
-        .filter_map(|n| {
-            let t = nth_heptagonal_number(n);
-            if (1000..10000).contains(&t) {
-                Some(t)
-            } else {
-                None
-            }
-        })
-        .collect::<BTreeSet<_>>();
-    let octagonal = (1..10000)
-        .filter_map(|n| {
-            let t = nth_octagonal_number(n);
-            if (1000..10000).contains(&t) {
-                Some(t)
-            } else {
-                None
-            }
+    let polygonals = (3..=8)
+        .map(|sides| {
+            Polygonal::new(sides)
+                .in_range(1000..10000)
+                .collect::<BTreeSet<_>>()
         })
-        .collect::<BTreeSet<_>>();
-    let all = triangle
-        .iter()
-        .chain(square.iter())
-        .chain(pentagonal.iter())
-        .chain(hexagonal.iter())
-        .chain(heptagonal.iter())
-        .chain(octagonal.iter())
-        .collect::<BTreeSet<_>>();
+        .collect::<Vec<_>>();
+    let all = polygonals.iter().flatten().collect::<BTreeSet<_>>();
     let r = all
         .iter()
         .map(|n| [*n])
@@ -114,12 +51,11 @@ fn main() {
             let mut masks = l
                 .iter()
                 .map(|n| {
-                    (if triangle.contains(n) { 0b1_u64 } else { 0b0 }
-                        | if square.contains(n) { 0b10 } else { 0b0 }
-                        | if pentagonal.contains(n) { 0b100 } else { 0b0 }
-                        | if hexagonal.contains(n) { 0b1000 } else { 0b0 }
-                        | if heptagonal.contains(n) { 0b10000 } else { 0b0 }
-                        | if octagonal.contains(n) { 0b100000 } else { 0b0 })
+                    polygonals
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, polygonal)| polygonal.contains(n))
+                        .fold(0_u64, |mask, (i, _)| mask | 1 << i)
                 })
                 .collect::<Vec<_>>();
             let mut r = true;
@@ -5,7 +5,7 @@ fn main() {
     println!("{}", py.biggest_sum());
 }
 
-const PYRAMID: [u64; 5050] = [
+static PYRAMID: [u64; 5050] = [
     59, 73, 41, 52, 40, 9, 26, 53, 6, 34, 10, 51, 87, 86, 81, 61, 95, 66, 57, 25, 68, 90, 81, 80,
     38, 92, 67, 73, 30, 28, 51, 76, 81, 18, 75, 44, 84, 14, 95, 87, 62, 81, 17, 78, 58, 21, 46, 71,
     58, 2, 79, 62, 39, 31, 9, 56, 34, 35, 53, 78, 31, 81, 18, 90, 93, 15, 78, 53, 4, 21, 84, 93,
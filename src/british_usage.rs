@@ -86,8 +86,7 @@ impl From<u64> for BritishUsage {
                         let text = if remain == 0 {
                             Self::tens(tens)
                         } else {
-                            vec![Self::tens(tens), "-".to_owned(), Self::single_digit(remain)]
-                                .join("")
+                            [Self::tens(tens), "-".to_owned(), Self::single_digit(remain)].join("")
                         };
                         remaining = 0;
                         list.push(text)
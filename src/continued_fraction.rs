@@ -0,0 +1,295 @@
+use std::fmt::Display;
+
+use crate::{isqrt, BigUInt};
+
+/// Simple continued fraction, `a0 + 1 / (a1 + 1 / (a2 + ...))`.
+///
+/// Represents finite expansions of rationals, eventually periodic expansions of quadratic
+/// irrationals like √n, and expansions whose repeating terms grow arithmetically, like e.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinuedFraction {
+    prefix: Vec<u64>,
+    period: Vec<u64>,
+    /// How much each term of `period` grows with every repetition, all zeros when periodic.
+    growth: Vec<u64>,
+}
+
+impl ContinuedFraction {
+    /// Create a continued fraction from its terms, `prefix` followed by `period` repeating
+    /// forever.
+    ///
+    /// # Panic
+    /// Panics if `prefix` is empty, or if any term after the first is zero.
+    pub fn new(prefix: Vec<u64>, period: Vec<u64>) -> Self {
+        Self::with_growth(prefix, period.clone(), vec![0; period.len()])
+    }
+
+    /// Create a continued fraction whose repeating terms grow arithmetically, `prefix` followed
+    /// by `period[j] + k * growth[j]` in the `k`-th repetition.
+    ///
+    /// # Panic
+    /// Panics if `prefix` is empty, if any term after the first is zero, or if `period` and
+    /// `growth` have different lengths.
+    pub fn with_growth(prefix: Vec<u64>, period: Vec<u64>, growth: Vec<u64>) -> Self {
+        if prefix.is_empty() {
+            panic!("Continued fraction needs at least one term.")
+        } else if prefix.iter().skip(1).chain(period.iter()).any(|a| *a == 0) {
+            panic!("Only the first term of a continued fraction can be zero.")
+        } else if period.len() != growth.len() {
+            panic!("Every repeating term needs a growth.")
+        }
+        Self {
+            prefix,
+            period,
+            growth,
+        }
+    }
+
+    /// Expand √n, which is `[a0; (a1, ..., 2 * a0)]` if `n` is not a perfect square.
+    pub fn sqrt(n: u64) -> Self {
+        let a0 = isqrt(n);
+        let mut period = vec![];
+        if a0 * a0 != n {
+            let (n, a0) = (u128::from(n), u128::from(a0));
+            let (mut m, mut d, mut a) = (0, 1, a0);
+            while a != 2 * a0 {
+                m = d * a - m;
+                d = (n - m * m) / d;
+                a = (a0 + m) / d;
+                period.push(a as u64);
+            }
+        }
+        Self {
+            prefix: vec![a0],
+            growth: vec![0; period.len()],
+            period,
+        }
+    }
+
+    /// Expand the rational `numer / denom` into its finite continued fraction.
+    ///
+    /// # Panic
+    /// Panics if `denom` is zero.
+    pub fn from_ratio(numer: u64, denom: u64) -> Self {
+        if denom == 0 {
+            panic!("Zero division.")
+        }
+        let mut prefix = vec![];
+        let (mut numer, mut denom) = (numer, denom);
+        while denom != 0 {
+            prefix.push(numer / denom);
+            (numer, denom) = (denom, numer % denom);
+        }
+        Self {
+            prefix,
+            period: vec![],
+            growth: vec![],
+        }
+    }
+
+    /// Expand Euler's number, `[2; 1, 2, 1, 1, 4, 1, 1, 6, 1, ...]`.
+    pub fn e() -> Self {
+        Self::with_growth(vec![2], vec![1, 2, 1], vec![0, 2, 0])
+    }
+
+    /// Get the terms before the periodic part.
+    pub fn prefix(&self) -> &[u64] {
+        &self.prefix
+    }
+
+    /// Get the repeating terms, `None` if the expansion is not periodic.
+    pub fn period(&self) -> Option<&[u64]> {
+        (!self.period.is_empty() && !self.is_growing()).then_some(self.period.as_slice())
+    }
+
+    /// Checks if the expansion has a finite number of terms, which happens only for rationals.
+    pub fn is_finite(&self) -> bool {
+        self.period.is_empty()
+    }
+
+    /// Checks if the repeating terms grow with every repetition.
+    fn is_growing(&self) -> bool {
+        self.growth.iter().any(|g| *g != 0)
+    }
+
+    /// Get the `i`-th term, `None` if the expansion has fewer terms.
+    pub fn term(&self, i: usize) -> Option<u64> {
+        if i < self.prefix.len() {
+            Some(self.prefix[i])
+        } else if self.period.is_empty() {
+            None
+        } else {
+            let j = i - self.prefix.len();
+            let (repetition, k) = (j / self.period.len(), j % self.period.len());
+            Some(self.period[k] + repetition as u64 * self.growth[k])
+        }
+    }
+
+    /// Iterate over the terms.
+    pub fn terms(&self) -> Terms<'_> {
+        Terms {
+            fraction: self,
+            index: 0,
+        }
+    }
+
+    /// Iterate over the convergents as `(numerator, denominator)` pairs.
+    ///
+    /// ```
+    /// use project_euler::{BigUInt, ContinuedFraction};
+    /// let root2 = ContinuedFraction::sqrt(2);
+    /// assert_eq!(root2.convergents().nth(3), Some((BigUInt::from(17_u64), BigUInt::from(12_u64))));
+    /// ```
+    pub fn convergents(&self) -> Convergents<'_> {
+        Convergents {
+            terms: self.terms(),
+            previous: (BigUInt::default(), BigUInt::from(1_u64)),
+            current: (BigUInt::from(1_u64), BigUInt::default()),
+        }
+    }
+}
+
+impl Display for ContinuedFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |terms: &[u64]| {
+            terms
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        write!(f, "[{}", self.prefix[0])?;
+        if self.is_growing() {
+            let terms = self
+                .terms()
+                .skip(1)
+                .take(self.prefix.len() - 1 + 2 * self.period.len())
+                .collect::<Vec<_>>();
+            write!(f, "; {}, ...", join(&terms))?;
+        } else if self.prefix.len() > 1 || !self.period.is_empty() {
+            write!(f, "; {}", join(&self.prefix[1..]))?;
+            if !self.period.is_empty() {
+                if self.prefix.len() > 1 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "({})", join(&self.period))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Iterator over the terms of a [`ContinuedFraction`].
+#[derive(Debug, Clone)]
+pub struct Terms<'a> {
+    fraction: &'a ContinuedFraction,
+    index: usize,
+}
+
+impl Iterator for Terms<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.fraction.term(self.index)?;
+        self.index += 1;
+        Some(term)
+    }
+}
+
+/// Iterator over the convergents of a [`ContinuedFraction`].
+#[derive(Debug, Clone)]
+pub struct Convergents<'a> {
+    terms: Terms<'a>,
+    previous: (BigUInt, BigUInt),
+    current: (BigUInt, BigUInt),
+}
+
+impl Iterator for Convergents<'_> {
+    type Item = (BigUInt, BigUInt);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = BigUInt::from(self.terms.next()?);
+        let next = (
+            a.clone() * self.current.0.clone() + &self.previous.0,
+            a * self.current.1.clone() + &self.previous.1,
+        );
+        self.previous = std::mem::replace(&mut self.current, next);
+        Some(self.current.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sqrt_test() {
+        let root23 = ContinuedFraction::sqrt(23);
+        assert_eq!(root23.prefix(), &[4]);
+        assert_eq!(root23.period(), Some([1, 3, 1, 8].as_slice()));
+        assert_eq!(root23.to_string(), "[4; (1, 3, 1, 8)]");
+        assert_eq!(
+            root23.terms().take(7).collect::<Vec<_>>(),
+            vec![4, 1, 3, 1, 8, 1, 3]
+        );
+
+        let root16 = ContinuedFraction::sqrt(16);
+        assert!(root16.is_finite());
+        assert_eq!(root16.to_string(), "[4]");
+
+        let odd_periods = (2..=13)
+            .filter(|n| {
+                ContinuedFraction::sqrt(*n)
+                    .period()
+                    .is_some_and(|p| p.len() % 2 == 1)
+            })
+            .count();
+        assert_eq!(odd_periods, 4);
+        assert_eq!(
+            ContinuedFraction::sqrt(u64::MAX).period(),
+            Some([1, 8589934590].as_slice())
+        );
+    }
+
+    #[test]
+    fn from_ratio_test() {
+        let fraction = ContinuedFraction::from_ratio(415, 93);
+        assert_eq!(fraction.prefix(), &[4, 2, 6, 7]);
+        assert_eq!(fraction.period(), None);
+        assert_eq!(fraction.to_string(), "[4; 2, 6, 7]");
+        assert_eq!(
+            fraction.convergents().last(),
+            Some((BigUInt::from(415_u64), BigUInt::from(93_u64)))
+        );
+        assert_eq!(ContinuedFraction::from_ratio(0, 5).prefix(), &[0]);
+        assert_eq!(
+            ContinuedFraction::new(vec![1, 2], vec![3]).to_string(),
+            "[1; 2, (3)]"
+        );
+    }
+
+    #[test]
+    fn e_test() {
+        let e = ContinuedFraction::e();
+        assert!(!e.is_finite());
+        assert_eq!(e.period(), None);
+        assert_eq!(e.to_string(), "[2; 1, 2, 1, 1, 4, 1, ...]");
+        assert_eq!(
+            e.terms().take(10).collect::<Vec<_>>(),
+            vec![2, 1, 2, 1, 1, 4, 1, 1, 6, 1]
+        );
+        assert_eq!(
+            e.convergents().nth(9),
+            Some((BigUInt::from(1457_u64), BigUInt::from(536_u64)))
+        );
+        assert_eq!(e.convergents().nth(99).unwrap().0.sum_of_digits(), 272);
+
+        // e^(1/2) = [1; 1, 1, 1, 5, 1, 1, 9, 1, 1, 13, ...]
+        let root_e = ContinuedFraction::with_growth(vec![1], vec![1, 1, 1], vec![4, 0, 0]);
+        assert_eq!(
+            root_e.terms().take(11).collect::<Vec<_>>(),
+            vec![1, 1, 1, 1, 5, 1, 1, 9, 1, 1, 13]
+        );
+        assert_eq!(root_e.to_string(), "[1; 1, 1, 1, 5, 1, 1, ...]");
+    }
+}
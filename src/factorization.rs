@@ -0,0 +1,228 @@
+use crate::{greatest_common_divisor, miller_rabin, mod_int::mul_mod};
+
+/// Primes below this bound are removed by trial division before using Pollard's rho.
+const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+/// Prime factorization of a positive integer.
+///
+/// Holds the prime factors in ascending order, each with its exponent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization(Vec<(u64, u32)>);
+
+impl Factorization {
+    /// Factors `n` using trial division for small factors, and Pollard-Brent rho with
+    /// Miller-Rabin for the rest.
+    ///
+    /// # Panic
+    /// Panics if `n` is zero.
+    pub fn new(n: u64) -> Self {
+        if n == 0 {
+            panic!("Zero has no prime factorization.")
+        }
+
+        let mut primes = vec![];
+        let mut rest = n;
+        for p in [2, 3, 5]
+            .into_iter()
+            .chain((7..TRIAL_DIVISION_BOUND).step_by(2))
+        {
+            if p * p > rest {
+                break;
+            }
+            while rest.is_multiple_of(p) {
+                primes.push(p);
+                rest /= p;
+            }
+        }
+        if rest > 1 {
+            let mut pending = vec![rest];
+            while let Some(m) = pending.pop() {
+                if m < TRIAL_DIVISION_BOUND * TRIAL_DIVISION_BOUND || miller_rabin(m) {
+                    primes.push(m);
+                } else {
+                    let d = pollard_brent(m);
+                    pending.push(d);
+                    pending.push(m / d);
+                }
+            }
+        }
+        primes.sort();
+
+        let mut factors: Vec<(u64, u32)> = vec![];
+        for p in primes {
+            match factors.last_mut() {
+                Some((last, e)) if *last == p => *e += 1,
+                _ => factors.push((p, 1)),
+            }
+        }
+        Self(factors)
+    }
+
+    /// Get the prime factors and their exponents, in ascending order of the primes.
+    pub fn factors(&self) -> &[(u64, u32)] {
+        &self.0
+    }
+
+    /// Get the distinct prime factors in ascending order.
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.0.iter().map(|(p, _)| *p)
+    }
+
+    /// Get the number that was factored.
+    pub fn value(&self) -> u64 {
+        self.0.iter().map(|(p, e)| p.pow(*e)).product()
+    }
+
+    /// Get the number of divisors, σ₀(n).
+    pub fn divisor_count(&self) -> u64 {
+        self.0.iter().map(|(_, e)| u64::from(*e) + 1).product()
+    }
+
+    /// Get the sum of divisors, σ₁(n).
+    pub fn divisor_sum(&self) -> u128 {
+        self.0
+            .iter()
+            .map(|(p, e)| (0..=*e).map(|i| u128::from(*p).pow(i)).sum::<u128>())
+            .product()
+    }
+
+    /// Get all divisors in ascending order, including 1 and the number itself.
+    pub fn divisors(&self) -> Vec<u64> {
+        let mut divisors = self.0.iter().fold(vec![1], |divisors, (p, e)| {
+            divisors
+                .iter()
+                .flat_map(|d| (0..=*e).map(move |i| d * p.pow(i)))
+                .collect()
+        });
+        divisors.sort();
+        divisors
+    }
+
+    /// Get Euler's totient, φ(n), the amount of numbers up to `n` that are coprime with it.
+    pub fn totient(&self) -> u64 {
+        self.0.iter().map(|(p, e)| (p - 1) * p.pow(e - 1)).product()
+    }
+
+    /// Get the radical, the product of the distinct prime factors.
+    pub fn radical(&self) -> u64 {
+        self.primes().product()
+    }
+
+    /// Get the Möbius function, μ(n), `0` if `n` has a squared prime factor, otherwise `1` or `-1`
+    /// for an even or odd number of prime factors.
+    pub fn mobius(&self) -> i8 {
+        if self.0.iter().any(|(_, e)| *e > 1) {
+            0
+        } else if self.0.len().is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl IntoIterator for Factorization {
+    type Item = (u64, u32);
+    type IntoIter = std::vec::IntoIter<(u64, u32)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Finds a non-trivial factor of the odd composite `n` using Brent's variant of Pollard's rho.
+fn pollard_brent(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+
+    let gcd = |a: u64, b: u64| greatest_common_divisor(&u128::from(a), &u128::from(b)) as u64;
+
+    for c in 1..n {
+        let f = |x: u64| ((u128::from(mul_mod(x, x, n)) + u128::from(c)) % u128::from(n)) as u64;
+        let (mut x, mut y, mut ys) = (2, 2, 2);
+        let mut q = 1;
+        let mut g = 1;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // The batch overshot, retrace it one step at a time.
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    n
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn factorization_test() {
+        assert_eq!(Factorization::new(1).factors(), &[]);
+        assert_eq!(Factorization::new(360).factors(), &[(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(
+            Factorization::new(600851475143).factors(),
+            &[(71, 1), (839, 1), (1471, 1), (6857, 1)]
+        );
+        assert_eq!(
+            Factorization::new(1_000_000_007 * 998_244_353).factors(),
+            &[(998_244_353, 1), (1_000_000_007, 1)]
+        );
+        assert_eq!(
+            Factorization::new(4_294_967_291 * 4_294_967_279).factors(),
+            &[(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+        assert_eq!(
+            Factorization::new(u64::MAX).factors(),
+            &[
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+        assert!((1..2000).all(|n| Factorization::new(n).value() == n));
+    }
+
+    #[test]
+    fn arithmetic_functions_test() {
+        let f = Factorization::new(12);
+        assert_eq!(f.divisor_count(), 6);
+        assert_eq!(f.divisor_sum(), 28);
+        assert_eq!(f.divisors(), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(f.totient(), 4);
+        assert_eq!(f.radical(), 6);
+        assert_eq!(f.mobius(), 0);
+        assert_eq!(Factorization::new(30).mobius(), -1);
+        assert_eq!(Factorization::new(1).mobius(), 1);
+        assert_eq!(Factorization::new(1).totient(), 1);
+        assert_eq!(Factorization::new(97).totient(), 96);
+    }
+}
@@ -1,43 +1,408 @@
+use std::ops::Range;
+
+use crate::{isqrt, BigUInt, Roots};
+
+/// Get the index `k` where `n` is the `k`th `s`-gonal number, `None` if `n` is not `s`-gonal.
+///
+/// Inverts `P(s, k) = ((s - 2) k² - (s - 4) k) / 2` exactly with an integer square root.
+///
+/// # Panic
+/// Panics if `s` is less than 3.
+///
+/// ```
+/// use project_euler::polygonal_index;
+/// assert_eq!(polygonal_index(5, 35), Some(5));
+/// assert_eq!(polygonal_index(5, 36), None);
+/// ```
+pub fn polygonal_index(s: u64, n: u64) -> Option<u64> {
+    if s < 3 {
+        panic!("Polygons need at least 3 sides.")
+    }
+    if n == 0 {
+        return Some(0);
+    }
+    // The discriminant only overflows a `u128` for polygons with more than about 2^61 sides.
+    let (wide_s, wide_n) = (u128::from(s), u128::from(n));
+    let fits = (wide_s.abs_diff(4).checked_pow(2))
+        .zip(
+            8_u128
+                .checked_mul(wide_s - 2)
+                .and_then(|x| x.checked_mul(wide_n)),
+        )
+        .and_then(|(a, b)| a.checked_add(b))
+        .is_some();
+    if fits {
+        invert_polygonal(wide_s, wide_n).map(|k| k as u64)
+    } else {
+        invert_polygonal(BigUInt::from(s), BigUInt::from(n)).and_then(|k| u64::try_from(&k).ok())
+    }
+}
+
+/// Solves `P(s, k) = n` for `k` with the quadratic formula, `k = (√Δ + s - 4) / (2 (s - 2))` where
+/// `Δ = (s - 4)² + 8 (s - 2) n`.
+///
+/// The caller makes sure `Δ` fits in `T`.
+fn invert_polygonal<T: Roots>(s: T, n: T) -> Option<T> {
+    let (two, four) = (T::from_u64(2), T::from_u64(4));
+    let shift = if s >= four {
+        s.clone() - four.clone()
+    } else {
+        four.clone() - s.clone()
+    };
+    let delta = shift.clone() * shift + T::from_u64(8) * (s.clone() - two.clone()) * n;
+    let root = isqrt(delta.clone());
+    // `√Δ > |s - 4|`, so the numerator is positive.
+    let numerator = root.clone() + s.clone() - four;
+    let denominator = two.clone() * (s - two);
+    (root.clone() * root == delta && numerator.clone() % denominator.clone() == T::zero())
+        .then(|| numerator / denominator)
+}
+
 /// Check if `n` is a triangle number.
 pub fn is_triangle_number(n: u64) -> bool {
-    let delta = 1 + 8 * n;
-    let x1 = (-1. + (delta as f64).sqrt()) / 2.;
-    let x2 = (-1. - (delta as f64).sqrt()) / 2.;
-    x1.fract() == 0.0 && x2.fract() == 0.0
+    polygonal_index(3, n).is_some()
+}
+
+/// Check if `n` is a square number.
+pub fn is_square(n: u64) -> bool {
+    isqrt(n).pow(2) == n
+}
+
+/// Check if `n` is a pentagonal number.
+pub fn is_pentagonal(n: u64) -> bool {
+    polygonal_index(5, n).is_some()
+}
+
+/// Check if `n` is a hexagonal number.
+pub fn is_hexagonal(n: u64) -> bool {
+    polygonal_index(6, n).is_some()
+}
+
+/// Check if `n` is a heptagonal number.
+pub fn is_heptagonal(n: u64) -> bool {
+    polygonal_index(7, n).is_some()
 }
 
-/// Get the `n`th triangle number
+/// Check if `n` is an octagonal number.
+pub fn is_octagonal(n: u64) -> bool {
+    polygonal_index(8, n).is_some()
+}
+
+/// Get the `n`th triangle number, the panicking [`Polygonal::nth`] with `0` for `n = 0`.
 #[inline(always)]
 pub fn nth_triangle_number(n: u64) -> u64 {
-    n * (n + 1) / 2
+    nth_polygonal(Polygonal::TRIANGLE, n)
 }
 
-/// Get the `n`th square number
+/// Get the `n`th square number, the panicking [`Polygonal::nth`] with `0` for `n = 0`.
 #[inline(always)]
 pub fn nth_square_number(n: u64) -> u64 {
-    n.pow(2)
+    nth_polygonal(Polygonal::SQUARE, n)
 }
 
-/// Get the `n`th pentagon numbers.
+/// Get the `n`th pentagonal number, the panicking [`Polygonal::nth`] with `0` for `n = 0`.
 #[inline(always)]
 pub fn nth_pentagonal_number(n: u64) -> u64 {
-    n * (3 * n - 1) / 2
+    nth_polygonal(Polygonal::PENTAGONAL, n)
 }
 
-/// Get the `n`th pentagon numbers.
+/// Get the `n`th hexagonal number, the panicking [`Polygonal::nth`] with `0` for `n = 0`.
 #[inline(always)]
 pub fn nth_hexagonal_number(n: u64) -> u64 {
-    n * (2 * n - 1)
+    nth_polygonal(Polygonal::HEXAGONAL, n)
 }
 
-/// Get the `n`th heptagonal numbers.
+/// Get the `n`th heptagonal number, the panicking [`Polygonal::nth`] with `0` for `n = 0`.
 #[inline(always)]
 pub fn nth_heptagonal_number(n: u64) -> u64 {
-    n * (5 * n - 3) / 2
+    nth_polygonal(Polygonal::HEPTAGONAL, n)
 }
 
-/// Get the `n`th pentagon numbers.
+/// Get the `n`th octagonal number, the panicking [`Polygonal::nth`] with `0` for `n = 0`.
 #[inline(always)]
 pub fn nth_octagonal_number(n: u64) -> u64 {
-    n * (3 * n - 2)
+    nth_polygonal(Polygonal::OCTAGONAL, n)
+}
+
+/// Get the `n`th polygonal number, extended with `P(s, 0) = 0`.
+///
+/// # Panic
+/// Panics if the result does not fit in a `u64`.
+fn nth_polygonal(polygonal: Polygonal, n: u64) -> u64 {
+    if n == 0 {
+        0
+    } else {
+        polygonal
+            .nth(n)
+            .expect("Polygonal number does not fit in a u64.")
+    }
+}
+
+/// Sequence of figurate numbers.
+///
+/// Every sequence is indexed from 1, the first number being `nth(1)`, so the index counts the
+/// layers of the figure.
+pub trait Figurate {
+    /// Get the `k`th number of the sequence, `None` if `k` is zero or the number does not fit in
+    /// a `u64`.
+    fn nth(&self, k: u64) -> Option<u64>;
+
+    /// Iterate over the sequence, starting from the first number and stopping at the last one
+    /// that fits in a `u64`.
+    fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        (1..).map_while(|k| self.nth(k))
+    }
+
+    /// Iterate over the numbers of the sequence inside `range`.
+    fn in_range(&self, range: Range<u64>) -> impl Iterator<Item = u64> + '_ {
+        self.iter()
+            .skip_while(move |n| *n < range.start)
+            .take_while(move |n| *n < range.end)
+    }
+}
+
+/// Polygonal numbers with `sides` sides, `P(s, k) = ((s - 2) k² - (s - 4) k) / 2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Polygonal {
+    sides: u64,
+}
+
+impl Polygonal {
+    pub const TRIANGLE: Self = Self { sides: 3 };
+    pub const SQUARE: Self = Self { sides: 4 };
+    pub const PENTAGONAL: Self = Self { sides: 5 };
+    pub const HEXAGONAL: Self = Self { sides: 6 };
+    pub const HEPTAGONAL: Self = Self { sides: 7 };
+    pub const OCTAGONAL: Self = Self { sides: 8 };
+
+    /// Create the sequence of `sides`-gonal numbers.
+    ///
+    /// # Panic
+    /// Panics if `sides` is less than 3.
+    pub fn new(sides: u64) -> Self {
+        if sides < 3 {
+            panic!("Polygons need at least 3 sides.")
+        }
+        Self { sides }
+    }
+
+    /// Get the number of sides.
+    pub fn sides(&self) -> u64 {
+        self.sides
+    }
+
+    /// Get the index of `n` in the sequence, see [`polygonal_index`].
+    pub fn index_of(&self, n: u64) -> Option<u64> {
+        polygonal_index(self.sides, n)
+    }
+
+    /// Checks if `n` is in the sequence.
+    pub fn contains(&self, n: u64) -> bool {
+        self.index_of(n).is_some()
+    }
+}
+
+impl Figurate for Polygonal {
+    fn nth(&self, k: u64) -> Option<u64> {
+        if k == 0 {
+            return None;
+        }
+        let (s, k) = (u128::from(self.sides), u128::from(k));
+        let n = ((s - 2) * (k - 1) + 2).checked_mul(k)? / 2;
+        u64::try_from(n).ok()
+    }
+
+    /// Iterate over the numbers of the sequence inside `range`.
+    ///
+    /// Starts from an index estimated with an integer square root instead of walking the
+    /// sequence from the beginning.
+    fn in_range(&self, range: Range<u64>) -> impl Iterator<Item = u64> + '_ {
+        // `(s - 2) k² <= start` guarantees `P(s, k) <= start`.
+        let first = isqrt(range.start / (self.sides - 2)).max(1);
+        (first..)
+            .map_while(|k| self.nth(k))
+            .skip_while(move |n| *n < range.start)
+            .take_while(move |n| *n < range.end)
+    }
+}
+
+/// Centered polygonal numbers with `sides` sides, `C(s, k) = s k (k - 1) / 2 + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CenteredPolygonal {
+    sides: u64,
+}
+
+impl CenteredPolygonal {
+    /// Create the sequence of centered `sides`-gonal numbers.
+    ///
+    /// # Panic
+    /// Panics if `sides` is less than 3.
+    pub fn new(sides: u64) -> Self {
+        if sides < 3 {
+            panic!("Polygons need at least 3 sides.")
+        }
+        Self { sides }
+    }
+
+    /// Get the number of sides.
+    pub fn sides(&self) -> u64 {
+        self.sides
+    }
+}
+
+impl Figurate for CenteredPolygonal {
+    fn nth(&self, k: u64) -> Option<u64> {
+        let (s, k) = (u128::from(self.sides), u128::from(k.checked_sub(1)?));
+        let n = s.checked_mul(k * (k + 1))? / 2 + 1;
+        u64::try_from(n).ok()
+    }
+}
+
+/// Tetrahedral numbers, `T(k) = k (k + 1) (k + 2) / 6`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tetrahedral;
+
+impl Figurate for Tetrahedral {
+    fn nth(&self, k: u64) -> Option<u64> {
+        if k == 0 {
+            return None;
+        }
+        let k = u128::from(k);
+        let n = (k * (k + 1)).checked_mul(k + 2)? / 6;
+        u64::try_from(n).ok()
+    }
+}
+
+/// Square pyramidal numbers, `P(k) = k (k + 1) (2k + 1) / 6`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SquarePyramidal;
+
+impl Figurate for SquarePyramidal {
+    fn nth(&self, k: u64) -> Option<u64> {
+        if k == 0 {
+            return None;
+        }
+        let k = u128::from(k);
+        let n = (k * (k + 1)).checked_mul(2 * k + 1)? / 6;
+        u64::try_from(n).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn polygonal_index_test() {
+        let nth = |s, k| match s {
+            3 => nth_triangle_number(k),
+            4 => nth_square_number(k),
+            5 => nth_pentagonal_number(k),
+            6 => nth_hexagonal_number(k),
+            7 => nth_heptagonal_number(k),
+            _ => nth_octagonal_number(k),
+        };
+        for s in 3..=8 {
+            let members = (1..200).map(|k| nth(s, k)).collect::<Vec<_>>();
+            for n in 1..=*members.last().unwrap() {
+                assert_eq!(
+                    polygonal_index(s, n),
+                    members.iter().position(|m| *m == n).map(|k| k as u64 + 1)
+                );
+            }
+        }
+        assert_eq!(polygonal_index(3, 0), Some(0));
+        assert_eq!(polygonal_index(10, 0), Some(0));
+        assert_eq!(polygonal_index(1000, 1000), Some(2));
+        assert_eq!(polygonal_index(u64::MAX, 1), Some(1));
+        assert_eq!(polygonal_index(u64::MAX, u64::MAX), Some(2));
+        assert_eq!(polygonal_index(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(polygonal_index(1 << 62, 3 * (1 << 62) - 3), Some(3));
+        assert_eq!(polygonal_index(1 << 62, 3 * (1 << 62) - 2), None);
+    }
+
+    #[test]
+    fn is_polygonal_test() {
+        assert!(is_square(u64::from(u32::MAX).pow(2)));
+        assert!(!is_square(u64::from(u32::MAX).pow(2) - 1));
+        assert!(!is_square(u64::MAX));
+        assert!(is_pentagonal(nth_pentagonal_number(2_000_000_000)));
+        assert!(!is_pentagonal(nth_pentagonal_number(2_000_000_000) - 1));
+        assert!(is_hexagonal(40755));
+        assert!(is_heptagonal(nth_heptagonal_number(1_000_000_000)));
+        assert!(is_octagonal(nth_octagonal_number(1_000_000_000)));
+        assert!(!is_octagonal(nth_octagonal_number(1_000_000_000) + 1));
+        assert!(is_triangle_number(nth_triangle_number(4_000_000_000)));
+    }
+
+    #[test]
+    fn figurate_test() {
+        assert_eq!(
+            Polygonal::new(5).iter().take(5).collect::<Vec<_>>(),
+            vec![1, 5, 12, 22, 35]
+        );
+        assert_eq!(
+            Polygonal::TRIANGLE.in_range(1000..1100).collect::<Vec<_>>(),
+            vec![1035, 1081]
+        );
+        for s in 3..=12 {
+            let polygonal = Polygonal::new(s);
+            for (lo, hi) in [(0, 50), (1, 2), (1000, 10000), (1234, 1235), (5, 5)] {
+                assert_eq!(
+                    polygonal.in_range(lo..hi).collect::<Vec<_>>(),
+                    polygonal
+                        .iter()
+                        .take_while(|n| *n < hi)
+                        .filter(|n| *n >= lo)
+                        .collect::<Vec<_>>()
+                );
+            }
+            assert!(polygonal.iter().take(100).all(|n| polygonal.contains(n)));
+        }
+        assert_eq!(
+            CenteredPolygonal::new(6).iter().take(5).collect::<Vec<_>>(),
+            vec![1, 7, 19, 37, 61]
+        );
+        assert_eq!(
+            CenteredPolygonal::new(4)
+                .in_range(10..100)
+                .collect::<Vec<_>>(),
+            vec![13, 25, 41, 61, 85]
+        );
+        assert_eq!(
+            Tetrahedral.iter().take(5).collect::<Vec<_>>(),
+            vec![1, 4, 10, 20, 35]
+        );
+        assert_eq!(
+            SquarePyramidal.iter().take(5).collect::<Vec<_>>(),
+            vec![1, 5, 14, 30, 55]
+        );
+    }
+
+    #[test]
+    fn figurate_nth_test() {
+        assert_eq!(Polygonal::TRIANGLE.nth(0), None);
+        assert_eq!(CenteredPolygonal::new(6).nth(0), None);
+        assert_eq!(Tetrahedral.nth(0), None);
+        assert_eq!(SquarePyramidal.nth(0), None);
+        assert_eq!(Polygonal::TRIANGLE.nth(1), Some(1));
+        assert_eq!(CenteredPolygonal::new(6).nth(1), Some(1));
+        assert_eq!(
+            Polygonal::SQUARE.nth(u64::from(u32::MAX)),
+            Some(u64::from(u32::MAX).pow(2))
+        );
+        assert_eq!(Polygonal::SQUARE.nth(1 << 32), None);
+        assert_eq!(Polygonal::new(u64::MAX).nth(2), Some(u64::MAX));
+        assert_eq!(Polygonal::new(u64::MAX).nth(3), None);
+        assert_eq!(CenteredPolygonal::new(u64::MAX).nth(3), None);
+        assert_eq!(Tetrahedral.nth(u64::MAX), None);
+        assert_eq!(Polygonal::new(u64::MAX).iter().count(), 2);
+        assert_eq!(nth_triangle_number(0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_overflow_test() {
+        nth_square_number(1 << 32);
+    }
 }
@@ -0,0 +1,400 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use crate::{BigInt, BigUInt};
+
+/// Common operations over the integer types of the crate, the primitive integers, `BigUInt`
+/// and `BigInt`.
+pub trait Integer:
+    Clone
+    + Ord
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Converts a small value into this type.
+    fn from_u64(n: u64) -> Self;
+
+    /// Checks if the value is less than zero.
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    /// Get the absolute value.
+    fn abs(self) -> Self {
+        self
+    }
+
+    /// Get the greatest common divisor of `self` and `rhs`, always non-negative.
+    fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.clone().abs(), rhs.clone().abs());
+        while b != Self::zero() {
+            let r = a % b.clone();
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Get the least common multiple of `self` and `rhs`, always non-negative.
+    fn lcm(&self, rhs: &Self) -> Self {
+        if *self == Self::zero() || *rhs == Self::zero() {
+            Self::zero()
+        } else {
+            (self.clone() / self.gcd(rhs) * rhs.clone()).abs()
+        }
+    }
+}
+
+/// Implements [`Integer`] for unsigned primitive integers.
+macro_rules! impl_unsigned_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_u64(n: u64) -> Self {
+                    n as $t
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`Integer`] for signed primitive integers.
+macro_rules! impl_signed_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_u64(n: u64) -> Self {
+                    n as $t
+                }
+
+                fn is_negative(&self) -> bool {
+                    *self < 0
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_integer!(u8, u16, u32, u64, u128, usize);
+impl_signed_integer!(i8, i16, i32, i64, i128, isize);
+
+impl Integer for BigUInt {
+    fn zero() -> Self {
+        BigUInt::default()
+    }
+
+    fn one() -> Self {
+        BigUInt::from(1_u64)
+    }
+
+    fn from_u64(n: u64) -> Self {
+        BigUInt::from(n)
+    }
+}
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1_i64)
+    }
+
+    fn from_u64(n: u64) -> Self {
+        BigInt::from(BigUInt::from(n))
+    }
+
+    fn is_negative(&self) -> bool {
+        BigInt::is_negative(self)
+    }
+
+    fn abs(self) -> Self {
+        BigInt::abs(&self)
+    }
+}
+
+/// Get the greatest common divisor of `a` and `b`, always non-negative.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// Get the least common multiple of `a` and `b`, always non-negative.
+///
+/// ```
+/// use project_euler::lcm;
+/// assert_eq!((1..=10_u64).fold(1, lcm), 2520);
+/// ```
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a.lcm(&b)
+}
+
+/// Get `(g, x, y)` where `g` is the greatest common divisor of `a` and `b`, and `x` and `y` are
+/// Bézout coefficients, `a * x + b * y = g`.
+///
+/// The coefficients are the minimal ones, `|x| <= |b / g|` and `|y| <= |a / g|`, so they never
+/// overflow.
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (g, s, t, x_negative) = extended_gcd_unsigned(a.clone(), b.clone());
+    let (x, y) = if x_negative { (-s, t) } else { (s, -t) };
+    let x = if a.is_negative() { -x } else { x };
+    let y = if b.is_negative() { -y } else { y };
+    (g, x, y)
+}
+
+/// Get `(g, x, y, x_negative)`, the [`extended_gcd`] of `|a|` and `|b|` for types without
+/// negative numbers, like the unsigned integers and `BigUInt`.
+///
+/// `x` and `y` are the magnitudes of the Bézout coefficients, which have opposite signs: if
+/// `x_negative` is false, `|a| * x - |b| * y = g`, otherwise `|b| * y - |a| * x = g`.
+///
+/// ```
+/// use project_euler::extended_gcd_unsigned;
+/// assert_eq!(extended_gcd_unsigned(240_u32, 46), (2, 9, 47, true));
+/// assert_eq!(extended_gcd_unsigned(46_u32, 240), (2, 47, 9, false));
+/// ```
+pub fn extended_gcd_unsigned<T: Integer>(a: T, b: T) -> (T, T, T, bool) {
+    bezout_magnitudes(a.abs(), b.abs())
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the Chinese remainder theorem.
+///
+/// The moduli don't need to be coprime. Returns `(x, m)` where `m` is the least common multiple of
+/// the moduli and `x` is the unique solution in `0..m`. The least common multiple must fit in `T`,
+/// intermediate products never exceed it.
+///
+/// ```
+/// use project_euler::crt;
+/// assert_eq!(crt(&[(2_u64, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+/// assert_eq!(crt(&[(1_u64, 4), (3, 6)]), Ok((9, 12)));
+/// assert!(crt(&[(1_u64, 4), (2, 6)]).is_err());
+/// ```
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Result<(T, T), String> {
+    let mut solution = (T::zero(), T::one());
+    for (residue, modulus) in congruences {
+        if *modulus <= T::zero() {
+            return Err(format!("Modulus {modulus} is not positive."));
+        }
+        let (x, m) = solution;
+        let residue = residue.clone() % modulus.clone();
+        let residue = if residue.is_negative() {
+            residue + modulus.clone()
+        } else {
+            residue
+        };
+
+        let g = m.gcd(modulus);
+        let x_rem = x.clone() % modulus.clone();
+        let diff = if residue >= x_rem {
+            residue.clone() - x_rem
+        } else {
+            modulus.clone() - (x_rem - residue.clone())
+        };
+        if diff.clone() % g.clone() != T::zero() {
+            return Err(format!(
+                "Congruence x ≡ {residue} (mod {modulus}) is inconsistent with x ≡ {x} (mod {m})."
+            ));
+        }
+
+        let reduced_modulus = modulus.clone() / g.clone();
+        let step = m.clone() / g.clone() % reduced_modulus.clone();
+        let (_, s, _, odd) = bezout_magnitudes(step, reduced_modulus.clone());
+        let s = s % reduced_modulus.clone();
+        let inverse = if odd && s != T::zero() {
+            reduced_modulus.clone() - s
+        } else {
+            s
+        };
+        let k = mul_mod(
+            diff / g % reduced_modulus.clone(),
+            inverse,
+            &reduced_modulus,
+        );
+        solution = (x + m.clone() * k, m * reduced_modulus);
+    }
+    Ok(solution)
+}
+
+/// Multiplies `a` and `b`, both less than `modulus`, modulo `modulus` by doubling, so no value
+/// ever exceeds `modulus`.
+fn mul_mod<T: Integer>(mut a: T, mut b: T, modulus: &T) -> T {
+    let two = T::from_u64(2);
+    let add_mod = |a: T, b: T| {
+        let gap = modulus.clone() - b.clone();
+        if a >= gap {
+            a - gap
+        } else {
+            a + b
+        }
+    };
+    let mut product = T::zero();
+    while b != T::zero() {
+        if b.clone() % two.clone() == T::one() {
+            product = add_mod(product, a.clone());
+        }
+        a = add_mod(a.clone(), a);
+        b = b / two.clone();
+    }
+    product
+}
+
+/// Runs the extended Euclidean algorithm on non-negative `a` and `b` keeping only the magnitudes
+/// of the coefficients, so it works on unsigned types.
+///
+/// Returns `(g, s, t, odd)` where `a * s - b * t = g` if `odd` is false, and `b * t - a * s = g`
+/// otherwise.
+fn bezout_magnitudes<T: Integer>(a: T, b: T) -> (T, T, T, bool) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (T::one(), T::zero());
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    let mut odd = false;
+    while r1 != T::zero() {
+        let q = r0.clone() / r1.clone();
+        (r0, r1) = (r1.clone(), r0 % r1);
+        (s0, s1) = (s1.clone(), s0 + q.clone() * s1);
+        (t0, t1) = (t1.clone(), t0 + q * t1);
+        odd = !odd;
+    }
+    (r0, s0, t0, odd)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm_test() {
+        assert_eq!(gcd(12_u8, 18), 6);
+        assert_eq!(gcd(-12_i32, 18), 6);
+        assert_eq!(gcd(0_u64, 7), 7);
+        assert_eq!(gcd(0_i16, 0), 0);
+        assert_eq!(lcm(4_usize, 6), 12);
+        assert_eq!(lcm(-4_i64, 6), 12);
+        assert_eq!(lcm(0_u32, 6), 0);
+        assert_eq!(
+            gcd(
+                BigUInt::from("1000000000000000000000000000000"),
+                BigUInt::from("15000000000000000000000")
+            ),
+            BigUInt::from("5000000000000000000000")
+        );
+        assert_eq!(
+            (1..=100_u64).map(BigUInt::from).reduce(lcm).unwrap(),
+            BigUInt::from("69720375229712477164533808935312303556800")
+        );
+    }
+
+    #[test]
+    fn extended_gcd_test() {
+        for a in -30_i64..30 {
+            for b in -30..30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(g, gcd(a, b));
+                assert_eq!(a * x + b * y, g);
+            }
+        }
+        assert_eq!(extended_gcd(240_i32, 46), (2, -9, 47));
+        let (g, x, y) = extended_gcd(i128::from(u64::MAX), i128::from(u64::MAX - 1));
+        assert_eq!((g, x, y), (1, 1, -1));
+        for a in 0..30_u8 {
+            for b in 0..30 {
+                let (g, x, y, x_negative) = extended_gcd_unsigned(a, b);
+                assert_eq!(g, gcd(a, b));
+                let (ax, by) = (u32::from(a) * u32::from(x), u32::from(b) * u32::from(y));
+                if x_negative {
+                    assert_eq!(by - ax, u32::from(g));
+                } else {
+                    assert_eq!(ax - by, u32::from(g));
+                }
+            }
+        }
+        assert_eq!(
+            extended_gcd_unsigned(u64::MAX, u64::MAX - 1),
+            (1, 1, 1, false)
+        );
+        assert_eq!(
+            extended_gcd_unsigned(BigUInt::from(240_u64), BigUInt::from(46_u64)),
+            (
+                BigUInt::from(2_u64),
+                BigUInt::from(9_u64),
+                BigUInt::from(47_u64),
+                true
+            )
+        );
+        let (g, x, y) = extended_gcd(BigInt::from(240_i64), BigInt::from(46_i64));
+        assert_eq!(
+            (g, x, y),
+            (
+                BigInt::from(2_i64),
+                BigInt::from(-9_i64),
+                BigInt::from(47_i64)
+            )
+        );
+    }
+
+    #[test]
+    fn crt_test() {
+        assert_eq!(crt::<u64>(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(2_u64, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt(&[(-1_i32, 3), (-1, 5)]), Ok((14, 15)));
+        assert_eq!(crt(&[(3_u8, 4), (5, 6)]), Ok((11, 12)));
+        assert!(crt(&[(3_u8, 4), (4, 6)]).is_err());
+        assert!(crt(&[(3_i8, -4)]).is_err());
+        assert_eq!(
+            crt(&[(1_u64, 2), (0, (1 << 62) + 1)]),
+            Ok(((1 << 62) + 1, (1 << 63) + 2))
+        );
+        assert_eq!(crt(&[(100_u8, 127), (1, 2)]), Ok((227, 254)));
+        for a in 0..12_u32 {
+            for b in 0..18 {
+                let expected = (0..36).find(|x| x % 12 == a && x % 18 == b);
+                match crt(&[(a, 12), (b, 18)]) {
+                    Ok((x, m)) => assert_eq!((Some(x), m), (expected, 36)),
+                    Err(_) => assert_eq!(expected, None),
+                }
+            }
+        }
+        assert_eq!(
+            crt(&[
+                (BigUInt::from(1_u64), BigUInt::from(1_000_000_007_u64)),
+                (BigUInt::from(2_u64), BigUInt::from(998_244_353_u64))
+            ]),
+            Ok((
+                BigUInt::from(993328913953302350_u64),
+                BigUInt::from(998244359987710471_u64)
+            ))
+        );
+    }
+}
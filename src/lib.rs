@@ -4,48 +4,113 @@ use std::{
     ops::{Add, Range, RangeInclusive},
 };
 
+mod big_int;
+pub use big_int::*;
+
 mod big_uint;
 pub use big_uint::*;
 
 mod british_usage;
 pub use british_usage::*;
 
+mod continued_fraction;
+pub use continued_fraction::*;
+
+mod factorization;
+pub use factorization::*;
+
 mod figurate_numbers;
 pub use figurate_numbers::*;
 
+mod integer;
+pub use integer::*;
+
+mod mod_int;
+pub use mod_int::*;
+
+mod pell;
+pub use pell::*;
+
 mod poker;
 pub use poker::*;
 
+mod prime_counting;
+pub use prime_counting::*;
+
 mod pyramid;
 pub use pyramid::*;
 
 mod quadratic_formula;
 pub use quadratic_formula::*;
 
+mod ratio;
+pub use ratio::*;
+
+mod roots;
+pub use roots::*;
+
+mod sieve;
+pub use sieve::*;
+
 mod unit_fraction;
 pub use unit_fraction::*;
 
 /// Checks if number is multiple of either `3` or `5`.
 pub fn multiple_of_3_or_5(x: &u64) -> bool {
-    (x % 3 == 0) || (x % 5 == 0)
+    x.is_multiple_of(3) || x.is_multiple_of(5)
 }
 
 /// Checks if `f` is a factor of `x`.
 pub fn is_factor(x: u64, f: u64) -> bool {
-    x % f == 0
+    x.is_multiple_of(f)
 }
 
+/// Numbers below this limit are tested for primality by trial division, above it by Miller-Rabin.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 20;
+
 /// Checks if a number is prime
 pub fn is_prime(f: u64) -> bool {
     match f {
         2 | 3 => true,
-        a if a <= 1 || (a % 2 == 0) || (a % 3 == 0) => false,
-        a => !(5..=((a + 1) as f64).sqrt() as u64)
+        a if a <= 1 || a.is_multiple_of(2) || a.is_multiple_of(3) => false,
+        a if a >= TRIAL_DIVISION_LIMIT => miller_rabin(a),
+        a => !(5..=isqrt(a + 1))
             .step_by(6)
             .any(|i| a % i == 0 || a % (i + 2) == 0),
     }
 }
 
+/// Deterministic Miller-Rabin primality test, correct for every `u64`.
+///
+/// Uses the 7 bases found by Jim Sinclair, which have no strong pseudoprime below `2^64`.
+pub fn miller_rabin(n: u64) -> bool {
+    const BASES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+    if n < 2 {
+        return false;
+    } else if n < 4 {
+        return true;
+    } else if n.is_multiple_of(2) {
+        return false;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().map(|a| a % n).filter(|a| *a != 0).all(|a| {
+        let mut x = mod_int::pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mod_int::mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
 /// Checks if `f` is a prime factor of `x`.
 pub fn is_prime_factor(x: u64, f: u64) -> bool {
     is_factor(x, f) && is_prime(f)
@@ -54,7 +119,8 @@ pub fn is_prime_factor(x: u64, f: u64) -> bool {
 /// Returns the factors of a number, excluding 1 and itself.
 pub fn get_factors(x: u64) -> Vec<u64> {
     if x > 2 {
-        (2..=x.div_euclid(2)).filter(|f| is_factor(x, *f)).collect()
+        let divisors = Factorization::new(x).divisors();
+        divisors[1..divisors.len() - 1].to_vec()
     } else {
         vec![]
     }
@@ -62,28 +128,23 @@ pub fn get_factors(x: u64) -> Vec<u64> {
 
 /// Returns the prime factors of a number.
 pub fn get_prime_factors(x: u64) -> Vec<u64> {
-    primes_up_to(x)
-        .into_iter()
-        .filter(|f| is_prime_factor(x, *f))
-        .collect()
+    if x > 1 {
+        Factorization::new(x).primes().collect()
+    } else {
+        vec![]
+    }
 }
 
 /// Returns a map of prime factors and the amount of times the number can be factored by it
 pub fn get_prime_factors_frequencies(x: u64) -> HashMap<u64, u64> {
-    [2_u64]
-        .into_iter()
-        .chain((3..=x).step_by(2))
-        .fold((x, HashMap::new()), |(x, m), f| {
-            let mut nm = m;
-            let mut nx = x;
-            while nx % f == 0 {
-                nx = nx.div_euclid(f);
-                let ff = nm.get(&f).unwrap_or(&0);
-                nm.insert(f, ff + 1);
-            }
-            (nx, nm)
-        })
-        .1
+    if x > 1 {
+        Factorization::new(x)
+            .into_iter()
+            .map(|(p, e)| (p, u64::from(e)))
+            .collect()
+    } else {
+        HashMap::new()
+    }
 }
 
 /// Checks if number is palindrome.
@@ -94,25 +155,7 @@ pub fn is_palindrome(s: &str) -> bool {
 
 /// Get the smallest number that is multiple by all numbers from 1 through `x`.
 pub fn smallest_multiple_of_all_through_x(x: u64) -> u64 {
-    (1..=x)
-        .map(get_prime_factors_frequencies)
-        .reduce(|mut m, cm| {
-            for (k, v) in cm.iter() {
-                let ff = m.get(k).unwrap_or(&0);
-                if v.ge(ff) {
-                    m.insert(*k, *v)
-                } else {
-                    m.insert(*k, *ff)
-                };
-            }
-            m
-        })
-        .unwrap_or_default()
-        .iter()
-        .fold(1_u64, |mut p, (k, v)| {
-            p *= k.pow(*v as u32);
-            p
-        })
+    (1..=x).fold(1, lcm)
 }
 
 /// Get the sum of the squares from 1 through `x`.
@@ -127,105 +170,15 @@ pub fn square_of_sum(x: u64) -> u64 {
 
 /// Get the N-th prime.
 pub fn nth_prime(n: u64) -> u64 {
-    (0..n).fold(1_u64, |prev, _| match prev {
-        1 => 2,
-        2 => 3,
-        mut next => loop {
-            next += 2;
-            if is_prime(next) {
-                break next;
-            }
-        },
-    })
+    match n {
+        0 => 1,
+        n => Primes::new().nth(n as usize - 1).unwrap_or_default(),
+    }
 }
 
-/// Get all primes up to `n`.
-pub fn primes_up_to(limit: u64) -> BTreeSet<u64> {
-    const CONDITION_1: [u64; 8] = [1, 13, 17, 29, 37, 41, 49, 53];
-    const CONDITION_2: [u64; 4] = [7, 19, 31, 43];
-    const CONDITION_3: [u64; 4] = [11, 23, 47, 59];
-
-    let sqrt_limit = (limit as f64).sqrt() as u64 + 1;
-
-    let sieve = vec![1, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 49, 53, 59];
-    let mut is_prime = vec![false; limit as usize + 1];
-
-    // Condition 1
-    (1..sqrt_limit)
-        // Cartesian product of x E {1, 2, ...} and y E {1, 3, ...}
-        .flat_map(|x| {
-            (1..sqrt_limit)
-                .step_by(2)
-                .map(|y| (x, y))
-                .collect::<Vec<_>>()
-        })
-        // Calculate n <- 4 * x^2 + y^2
-        .map(|(x, y)| 4 * x.pow(2) + y.pow(2))
-        .filter(|n| n <= &limit)
-        .filter(|n| CONDITION_1.binary_search(&(n % 60)).is_ok())
-        .for_each(|n| is_prime[n as usize] = !is_prime[n as usize]);
-
-    // Condition 2
-    (1..sqrt_limit)
-        .step_by(2)
-        // Cartesian product of x E {1, 3, ...} and y E {2, 4, ...}
-        .flat_map(|x| {
-            (2..sqrt_limit)
-                .step_by(2)
-                .map(|y| (x, y))
-                .collect::<Vec<_>>()
-        })
-        // Calculate n <- 3 * x^2 + y^2
-        .map(|(x, y)| 3 * x.pow(2) + y.pow(2))
-        .filter(|n| n <= &limit)
-        .filter(|n| CONDITION_2.binary_search(&(n % 60)).is_ok())
-        .for_each(|n| is_prime[n as usize] = !is_prime[n as usize]);
-
-    // Condition 3
-    (2..sqrt_limit)
-        // Cartesian product of x E {2, 3, ...} and y E {x - 1, x - 3, ..., 1}
-        .flat_map(|x| {
-            (1..sqrt_limit)
-                .step_by(2)
-                .scan((), |_, sub| if sub > x { None } else { Some((x, x - sub)) })
-                .collect::<Vec<_>>()
-        })
-        // Calculate n <- 3 * x^2 - y^2
-        .map(|(x, y)| 3 * x.pow(2) - y.pow(2))
-        .filter(|n| n <= &limit)
-        .filter(|n| CONDITION_3.binary_search(&(n % 60)).is_ok())
-        .for_each(|n| is_prime[n as usize] ^= true);
-
-    // Composites
-    (0..sqrt_limit)
-        // Cartesian product of w E {0, 1, ...} and x E `sieve`
-        .flat_map(|w| sieve.iter().map(|x| (w, *x)).collect::<Vec<_>>())
-        // Calculate n <- 60 * w + x
-        .map(|(w, x)| 60 * w + x)
-        .filter(|n| n >= &7)
-        .filter(|n| n.pow(2) <= limit)
-        .for_each(|n| {
-            if is_prime[n as usize] {
-                (0..sqrt_limit)
-                    // Cartesian product of w E {0, 1, ...} and x E `sieve`
-                    .flat_map(|w| sieve.iter().map(|x| (w, *x)).collect::<Vec<_>>())
-                    // Calculate c <- n^2 * (60 * w + x)
-                    .map(|(w, x)| n.pow(2) * (60 * w + x))
-                    .filter(|c| c <= &limit)
-                    .for_each(|c| is_prime[c as usize] = false)
-            }
-        });
-
-    BTreeSet::from_iter(
-        [2, 3, 5].into_iter().chain(
-            (0..limit)
-                .flat_map(|w| sieve.iter().map(|x| (w, *x)).collect::<Vec<_>>())
-                .map(|(w, x)| 60 * w + x)
-                .filter(|n| n >= &7)
-                .filter(|n| n <= &limit)
-                .filter(|n| is_prime[*n as usize]),
-        ),
-    )
+/// Get all primes up to `limit`, inclusive, in ascending order.
+pub fn primes_up_to(limit: u64) -> Vec<u64> {
+    SegmentedSieve::new(0..limit.saturating_add(1)).collect()
 }
 
 /// Get the biggest product
@@ -351,10 +304,12 @@ pub fn grid_diagonal_left_product<const N: usize>(
 }
 
 /// Get the first Triangle number with N factors.
+///
+/// The search starts at the first Triangle number, `1`, as `0` has no factorization.
 pub fn first_triangle_number_with_over_n_factors(factor_count: u64) -> u64 {
-    (0..=factor_count.pow(2))
+    (1..=factor_count.pow(2))
         .map(nth_triangle_number)
-        .find(|t| get_factors(*t).len() as u64 >= (factor_count - 2))
+        .find(|t| Factorization::new(*t).divisor_count() >= factor_count)
         .unwrap_or(0)
 }
 
@@ -415,11 +370,11 @@ pub fn grid_walk_path_count(w: usize, h: usize) -> u128 {
 /// assert_eq!(days_in_year(2004), 366);
 /// ```
 pub fn days_in_year(year: u64) -> u64 {
-    let leap = if year % 400 == 0 {
+    let leap = if year.is_multiple_of(400) {
         1
-    } else if year % 100 == 0 {
+    } else if year.is_multiple_of(100) {
         0
-    } else if year % 4 == 0 {
+    } else if year.is_multiple_of(4) {
         1
     } else {
         0
@@ -548,6 +503,7 @@ pub fn uppercase_ascii_string_score(s: &str) -> u64 {
 /// A `Perfect` number has the sum of its factors equals to itself.
 /// A `Deficient` number has the sum of its factors less than itself.
 /// A `Abundant` number has the sum of its factors greater than itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NumberFactorSumClass {
     Abundant,
     Perfect,
@@ -556,13 +512,29 @@ pub enum NumberFactorSumClass {
 
 impl NumberFactorSumClass {
     /// Gets if number is Perfect, Abundant, or Deficient.
+    ///
+    /// `0` is Abundant, as every positive integer divides it.
     pub fn get_class(x: &u64) -> Self {
-        match (get_factors(*x).into_iter().sum::<u64>() + 1).cmp(x) {
+        if *x == 0 {
+            return Self::Abundant;
+        }
+        let aliquot_sum = Factorization::new(*x).divisor_sum() - u128::from(*x);
+        Self::from_aliquot_sum(u128::from(*x), aliquot_sum)
+    }
+
+    /// Gets the class of `n` from its aliquot sum, the sum of its divisors excluding itself.
+    pub fn from_aliquot_sum(n: u128, aliquot_sum: u128) -> Self {
+        match aliquot_sum.cmp(&n) {
             std::cmp::Ordering::Greater => Self::Abundant,
             std::cmp::Ordering::Equal => Self::Perfect,
             std::cmp::Ordering::Less => Self::Deficient,
         }
     }
+
+    /// Gets the class of every number from 1 through `limit`, without factoring each of them.
+    pub fn classify_up_to(limit: u64) -> Vec<(u64, Self)> {
+        MultiplicativeSieve::new(limit).classes().collect()
+    }
 }
 
 /// Get the permutantions of all digits up to `n`.
@@ -600,7 +572,7 @@ type Spiral = FlatMap<
 
 /// Create a Spiral generator.
 pub fn vertices_of_number_spiral(n: u64) -> Result<Spiral, String> {
-    if n % 2 == 0 {
+    if n.is_multiple_of(2) {
         Err("The sides of a Spiral have odd length.".into())
     } else {
         let shells_dimensions = (1_u64..n).step_by(2);
@@ -717,25 +689,20 @@ pub fn are_permutations(lhs: &u64, rhs: &u64) -> bool {
 }
 
 /// Get all solutions for a right triangle of integer perimeter `p`.
+///
+/// Solutions are `(a, b, c)` with `a >= b`, in ascending order of `b`.
 pub fn right_triangles_of_perimeter_p(p: u64) -> Vec<(u64, u64, u64)> {
-    let mut r = vec![];
-
-    let mut a = p as f64;
-    let mut b = 1.;
-
-    while a >= b {
-        let c = (a.powf(2.) + b.powf(2.)).sqrt();
-        match (a + b + c).total_cmp(&(p as f64)) {
-            std::cmp::Ordering::Less => b += 1.,
-            std::cmp::Ordering::Equal => {
-                r.push((a as u64, b as u64, c as u64));
-                b += 1.;
-            }
-            std::cmp::Ordering::Greater => a -= 1.,
-        }
-    }
-
-    r
+    // Substituting `c = p - a - b` into `a² + b² = c²` gives `a = p (p - 2b) / (2 (p - b))`.
+    (1..p / 2)
+        .map_while(|b| {
+            let numerator = u128::from(p) * u128::from(p - 2 * b);
+            let denominator = 2 * u128::from(p - b);
+            let a = (numerator / denominator) as u64;
+            (a >= b).then_some((a, b, numerator.is_multiple_of(denominator)))
+        })
+        .filter(|(_, _, exact)| *exact)
+        .map(|(a, b, _)| (a, b, p - a - b))
+        .collect()
 }
 
 /// Find sequencial numbers of length `window` that all have `factor` distinct prime factors.
@@ -760,9 +727,31 @@ pub fn sequencial_distinct_prime_factors(window: u64, factor: u64, stop: u64) ->
         .map(|d| d - (window - 1))
 }
 
-/// Calculate the power modulus.
+/// Get the multiplicative order of `a` modulo `modulus`, the smallest `k > 0` where `a^k = 1 (mod modulus)`.
+///
+/// Returns `None` if `a` and `modulus` are not coprime.
+pub fn multiplicative_order(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 1 {
+        return Some(1);
+    } else if greatest_common_divisor(&u128::from(a), &u128::from(modulus)) != 1 {
+        return None;
+    }
+    // The order divides `φ(modulus)`, so remove its prime factors while the power stays at 1.
+    let mut order = Factorization::new(modulus).totient();
+    for p in Factorization::new(order).primes() {
+        while order.is_multiple_of(p) && power_modulus(a, order / p, modulus) == 1 {
+            order /= p;
+        }
+    }
+    Some(order)
+}
+
+/// Calculate the power modulus, `x^pow mod modulus`.
+///
+/// # Panic
+/// Panics if `modulus` is zero.
 pub fn power_modulus(x: u64, pow: u64, modulus: u64) -> u64 {
-    (0..pow).fold(1, |prod, _| (prod * x) % modulus)
+    ModInt::new(x, modulus).pow(pow).value()
 }
 
 /// Generates a list of the sums of consecutives primes up to `threshold`.
@@ -770,9 +759,8 @@ pub fn power_modulus(x: u64, pow: u64, modulus: u64) -> u64 {
 /// The sequency with `skip` at `0` have the sums of \[2, 3, 5, ...\], `skip` at `1` has the sums
 /// of \[3, 5, 7, ...\], and so on.
 pub fn sum_of_consecutive_primes(threshold: u64, skip: usize) -> Vec<u64> {
-    let primes = primes_up_to(threshold);
-    primes
-        .iter()
+    Primes::new()
+        .take_while(|p| *p <= threshold)
         .skip(skip)
         .scan(0_u64, |sum, cur| {
             *sum += cur;
@@ -821,11 +809,7 @@ pub fn replace_digits(n: u64, mask: u64, digit: u8) -> u64 {
 
 /// Get greatest common divisor.
 pub fn greatest_common_divisor(lhs: &u128, rhs: &u128) -> u128 {
-    if rhs == &0 {
-        *lhs
-    } else {
-        greatest_common_divisor(rhs, &(lhs % rhs))
-    }
+    lhs.gcd(rhs)
 }
 
 /// Binomial distribution.
@@ -913,4 +897,43 @@ mod test {
             vec![-1, 0, 0, 0, 0, 0, 0, -1, 0, 2, 0, 0, 0, 0, 0, -1, 0, 0, 3, 0, 0, 0, 0, 0, 0]
         );
     }
+
+    #[test]
+    fn miller_rabin_test() {
+        let primes = primes_up_to(10_000);
+        assert!((0..10_000).all(|n| miller_rabin(n) == primes.binary_search(&n).is_ok()));
+        assert!(!miller_rabin(561));
+        assert!(!miller_rabin(2047));
+        assert!(!miller_rabin(3215031751));
+        assert!(miller_rabin((1 << 61) - 1));
+        assert!(miller_rabin(18446744073709551557));
+        assert!(!miller_rabin(u64::MAX));
+        assert!(is_prime(10_000_000_019));
+        assert!(!is_prime(4_294_967_297));
+    }
+
+    #[test]
+    fn sum_of_consecutive_primes_test() {
+        assert_eq!(sum_of_consecutive_primes(13, 0), vec![2, 5, 10, 17, 28, 41]);
+        assert_eq!(sum_of_consecutive_primes(12, 1), vec![3, 8, 15, 26]);
+        assert!(sum_of_consecutive_primes(1, 0).is_empty());
+    }
+
+    #[test]
+    fn multiplicative_order_test() {
+        for modulus in 1..200 {
+            for a in 0..modulus {
+                let brute_force =
+                    (1..=modulus).find(|k| power_modulus(a, *k, modulus) == 1 % modulus);
+                let coprime = greatest_common_divisor(&u128::from(a), &u128::from(modulus)) == 1;
+                assert_eq!(
+                    multiplicative_order(a, modulus),
+                    brute_force.filter(|_| coprime)
+                );
+            }
+        }
+        // Stepping through the powers one at a time would not finish for this prime.
+        let prime = 1_000_000_000_000_037;
+        assert_eq!(multiplicative_order(10, prime), Some((prime - 1) / 2));
+    }
 }
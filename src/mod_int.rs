@@ -0,0 +1,460 @@
+use std::{
+    fmt::Display,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Integer modulo a modulus chosen at runtime.
+///
+/// The value is always reduced, and every operation is safe for any modulus up to `u64::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    /// Create the residue of `value` modulo `modulus`.
+    ///
+    /// # Panic
+    /// Panics if `modulus` is zero.
+    pub fn new(value: u64, modulus: u64) -> Self {
+        if modulus == 0 {
+            panic!("Zero modulus.")
+        }
+        Self {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// Get the reduced value, in `0..modulus`.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Get the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Raise to the power of `exp` using square-and-multiply.
+    pub fn pow(&self, exp: u64) -> Self {
+        Self {
+            value: pow_mod(self.value, exp, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+
+    /// Get the multiplicative inverse, if the value is coprime with the modulus.
+    pub fn inverse(&self) -> Option<Self> {
+        inverse_mod(self.value, self.modulus).map(|value| Self {
+            value,
+            modulus: self.modulus,
+        })
+    }
+
+    /// Get the modulus shared by `self` and `rhs`.
+    ///
+    /// # Panic
+    /// Panics if the moduli are different.
+    fn common_modulus(&self, rhs: &Self) -> u64 {
+        if self.modulus != rhs.modulus {
+            panic!("Mismatched moduli.")
+        }
+        self.modulus
+    }
+}
+
+impl Display for ModInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl Add for ModInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let modulus = self.common_modulus(&rhs);
+        Self {
+            value: add_mod(self.value, rhs.value, modulus),
+            modulus,
+        }
+    }
+}
+
+impl AddAssign for ModInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for ModInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let modulus = self.common_modulus(&rhs);
+        Self {
+            value: sub_mod(self.value, rhs.value, modulus),
+            modulus,
+        }
+    }
+}
+
+impl SubAssign for ModInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for ModInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let modulus = self.common_modulus(&rhs);
+        Self {
+            value: mul_mod(self.value, rhs.value, modulus),
+            modulus,
+        }
+    }
+}
+
+impl MulAssign for ModInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for ModInt {
+    type Output = Self;
+
+    /// # Panic
+    /// Panics if `rhs` has no multiplicative inverse.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.common_modulus(&rhs);
+        match rhs.inverse() {
+            Some(inverse) => Mul::mul(self, inverse),
+            None => panic!("Zero division."),
+        }
+    }
+}
+
+impl DivAssign for ModInt {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for ModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: sub_mod(0, self.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+/// Sums into an `Option`, which is `None` for an empty iterator as there is no modulus to use.
+impl Sum<ModInt> for Option<ModInt> {
+    fn sum<I: Iterator<Item = ModInt>>(iter: I) -> Self {
+        iter.reduce(Add::add)
+    }
+}
+
+/// Multiplies into an `Option`, which is `None` for an empty iterator as there is no modulus to
+/// use.
+impl Product<ModInt> for Option<ModInt> {
+    fn product<I: Iterator<Item = ModInt>>(iter: I) -> Self {
+        iter.reduce(Mul::mul)
+    }
+}
+
+/// Integer modulo `M`, fixed at compile time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConstModInt<const M: u64>(u64);
+
+impl<const M: u64> ConstModInt<M> {
+    /// Create the residue of `value` modulo `M`.
+    pub fn new(value: u64) -> Self {
+        const { assert!(M > 0, "Zero modulus.") };
+        Self(value % M)
+    }
+
+    /// Get the reduced value, in `0..M`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Raise to the power of `exp` using square-and-multiply.
+    pub fn pow(&self, exp: u64) -> Self {
+        Self(pow_mod(self.0, exp, M))
+    }
+
+    /// Get the multiplicative inverse, if the value is coprime with `M`.
+    pub fn inverse(&self) -> Option<Self> {
+        inverse_mod(self.0, M).map(Self)
+    }
+}
+
+impl<const M: u64> From<u64> for ConstModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<ConstModInt<M>> for ModInt {
+    fn from(value: ConstModInt<M>) -> Self {
+        ModInt::new(value.0, M)
+    }
+}
+
+impl<const M: u64> Display for ConstModInt<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const M: u64> Add for ConstModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(add_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: u64> AddAssign for ConstModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: u64> Sub for ConstModInt<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(sub_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: u64> SubAssign for ConstModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const M: u64> Mul for ConstModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(mul_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: u64> MulAssign for ConstModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: u64> Div for ConstModInt<M> {
+    type Output = Self;
+
+    /// # Panic
+    /// Panics if `rhs` has no multiplicative inverse.
+    fn div(self, rhs: Self) -> Self::Output {
+        match rhs.inverse() {
+            Some(inverse) => Mul::mul(self, inverse),
+            None => panic!("Zero division."),
+        }
+    }
+}
+
+impl<const M: u64> DivAssign for ConstModInt<M> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const M: u64> Neg for ConstModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(sub_mod(0, self.0, M))
+    }
+}
+
+impl<const M: u64> Sum for ConstModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), Add::add)
+    }
+}
+
+impl<const M: u64> Product for ConstModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+/// Adds two reduced values without overflowing.
+fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// Subtracts two reduced values without overflowing.
+fn sub_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        modulus - b + a
+    }
+}
+
+/// Multiplies two values modulo `modulus` using a `u128` intermediate.
+pub(crate) fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(modulus)) as u64
+}
+
+/// Raises `base` to `exp` modulo `modulus` using square-and-multiply.
+pub(crate) fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut base = base % modulus;
+    let mut res = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod(res, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    res
+}
+
+/// Finds the inverse of `a` modulo `modulus` with the extended Euclidean algorithm.
+fn inverse_mod(a: u64, modulus: u64) -> Option<u64> {
+    let (mut r0, mut r1) = (i128::from(modulus), i128::from(a % modulus));
+    let (mut t0, mut t1) = (0_i128, 1_i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    if r0 != 1 {
+        None
+    } else {
+        Some(t0.rem_euclid(i128::from(modulus)) as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mod_int_test() {
+        let a = ModInt::new(10, 7);
+        let b = ModInt::new(5, 7);
+        assert_eq!(a.value(), 3);
+        assert_eq!((a + b).value(), 1);
+        assert_eq!((a - b).value(), 5);
+        assert_eq!((b - a).value(), 2);
+        assert_eq!((a * b).value(), 1);
+        assert_eq!((a / b).value(), 2);
+        assert_eq!((-a).value(), 4);
+        assert_eq!((-ModInt::new(0, 7)).value(), 0);
+        assert_eq!(a.pow(6).value(), 1);
+        assert_eq!(a.inverse(), Some(b));
+        assert_eq!(ModInt::new(4, 6).inverse(), None);
+        assert_eq!(ModInt::new(123, 1).pow(0).value(), 0);
+        assert_eq!(format!("{}", ModInt::new(1234, 1000)), "234");
+
+        let mut c = a;
+        c += b;
+        c *= b;
+        c -= a;
+        c /= b;
+        assert_eq!(c.value(), 6);
+    }
+
+    #[test]
+    fn mod_int_large_modulus_test() {
+        let m = u64::MAX - 58;
+        let a = ModInt::new(u64::MAX - 60, m);
+        let b = ModInt::new(u64::MAX - 59, m);
+        assert_eq!((a + b).value(), m - 3);
+        assert_eq!((a * b).value(), 2);
+        assert_eq!((a * a.inverse().unwrap()).value(), 1);
+        assert_eq!(ModInt::new(3, m).pow(m - 1).value(), 1);
+    }
+
+    #[test]
+    fn mod_int_pow_test() {
+        for m in 1..50 {
+            for x in 0..20 {
+                let naive = (0..20).fold(1 % m, |prod, _| prod * x % m);
+                assert_eq!(ModInt::new(x, m).pow(20).value(), naive);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_int_mismatched_moduli_test() {
+        let _ = ModInt::new(1, 7) + ModInt::new(1, 11);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_int_zero_division_test() {
+        let _ = ModInt::new(1, 6) / ModInt::new(3, 6);
+    }
+
+    #[test]
+    fn mod_int_sum_product_test() {
+        assert_eq!(
+            (1..=10)
+                .map(|n| ModInt::new(n, 1_000_000_007))
+                .product::<Option<ModInt>>()
+                .map(|p| p.value()),
+            Some(3628800)
+        );
+        assert_eq!(
+            (1..=10)
+                .map(|n| ModInt::new(n, 7))
+                .sum::<Option<ModInt>>()
+                .map(|s| s.value()),
+            Some(6)
+        );
+        assert_eq!(std::iter::empty().sum::<Option<ModInt>>(), None);
+        assert_eq!(std::iter::empty().product::<Option<ModInt>>(), None);
+    }
+
+    #[test]
+    fn const_mod_int_test() {
+        type Mod7 = ConstModInt<7>;
+        let a = Mod7::new(10);
+        let b = Mod7::from(5);
+        assert_eq!(a.value(), 3);
+        assert_eq!((a + b).value(), 1);
+        assert_eq!((a - b).value(), 5);
+        assert_eq!((a * b).value(), 1);
+        assert_eq!((a / b).value(), 2);
+        assert_eq!((-a).value(), 4);
+        assert_eq!(a.inverse(), Some(b));
+        assert_eq!(ModInt::from(a), ModInt::new(3, 7));
+        assert_eq!(std::iter::empty::<Mod7>().sum::<Mod7>().value(), 0);
+        assert_eq!(std::iter::empty::<Mod7>().product::<Mod7>().value(), 1);
+        assert_eq!(
+            (1..=1000)
+                .map(|n| ConstModInt::<10_000_000_000>::new(n).pow(n))
+                .sum::<ConstModInt<10_000_000_000>>()
+                .value(),
+            9110846700
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{BigUInt, ContinuedFraction};
+
+/// Get the fundamental solution `(x, y)` of Pell's equation, `x² - d·y² = 1`, the one with the
+/// smallest positive `x`.
+///
+/// Returns `None` if `d` is a perfect square, as there are only trivial solutions.
+///
+/// ```
+/// use project_euler::{solve_pell, BigUInt};
+/// assert_eq!(solve_pell(13), Some((BigUInt::from(649_u64), BigUInt::from(180_u64))));
+/// assert_eq!(solve_pell(16), None);
+/// ```
+pub fn solve_pell(d: u64) -> Option<(BigUInt, BigUInt)> {
+    let root = ContinuedFraction::sqrt(d);
+    let period = root.period()?.len();
+    // The convergent at the end of the period solves the negative equation when the period
+    // is odd, so it takes a second period to get to the positive one.
+    let index = if period % 2 == 0 {
+        period - 1
+    } else {
+        2 * period - 1
+    };
+    root.convergents().nth(index)
+}
+
+/// Get the fundamental solution `(x, y)` of the negative Pell's equation, `x² - d·y² = -1`.
+///
+/// Returns `None` if there are no solutions, which happens when the period of the continued
+/// fraction of √d is even.
+///
+/// ```
+/// use project_euler::{solve_negative_pell, BigUInt};
+/// assert_eq!(solve_negative_pell(13), Some((BigUInt::from(18_u64), BigUInt::from(5_u64))));
+/// assert_eq!(solve_negative_pell(3), None);
+/// ```
+pub fn solve_negative_pell(d: u64) -> Option<(BigUInt, BigUInt)> {
+    let root = ContinuedFraction::sqrt(d);
+    let period = root.period()?.len();
+    if period % 2 == 0 {
+        None
+    } else {
+        root.convergents().nth(period - 1)
+    }
+}
+
+/// Iterator over all the positive solutions of Pell's equation, `x² - d·y² = 1`, in ascending
+/// order, starting from the fundamental one.
+///
+/// Each solution is obtained from the previous with `x + y√d = (x₁ + y₁√d)^k`.
+#[derive(Debug, Clone)]
+pub struct PellSolutions {
+    d: BigUInt,
+    fundamental: (BigUInt, BigUInt),
+    current: (BigUInt, BigUInt),
+}
+
+impl PellSolutions {
+    /// Create the iterator over the solutions for `d`, `None` if `d` is a perfect square.
+    pub fn new(d: u64) -> Option<Self> {
+        let fundamental = solve_pell(d)?;
+        Some(Self {
+            d: BigUInt::from(d),
+            fundamental,
+            current: (BigUInt::from(1_u64), BigUInt::default()),
+        })
+    }
+}
+
+impl Iterator for PellSolutions {
+    type Item = (BigUInt, BigUInt);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x1, y1) = &self.fundamental;
+        let (x, y) = &self.current;
+        self.current = (
+            x1.clone() * x.clone() + self.d.clone() * y1.clone() * y.clone(),
+            x1.clone() * y.clone() + y1.clone() * x.clone(),
+        );
+        Some(self.current.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pell_value(d: u64, (x, y): &(BigUInt, BigUInt)) -> (BigUInt, BigUInt) {
+        (
+            x.clone() * x.clone(),
+            BigUInt::from(d) * y.clone() * y.clone(),
+        )
+    }
+
+    #[test]
+    fn solve_pell_test() {
+        assert_eq!(solve_pell(0), None);
+        assert_eq!(solve_pell(1), None);
+        assert_eq!(
+            solve_pell(2),
+            Some((BigUInt::from(3_u64), BigUInt::from(2_u64)))
+        );
+        assert_eq!(
+            solve_pell(7),
+            Some((BigUInt::from(8_u64), BigUInt::from(3_u64)))
+        );
+        assert_eq!(
+            solve_pell(61),
+            Some((BigUInt::from(1766319049_u64), BigUInt::from(226153980_u64)))
+        );
+        for d in 2..200 {
+            if let Some(solution) = solve_pell(d) {
+                let (x2, dy2) = pell_value(d, &solution);
+                assert_eq!(x2, dy2 + BigUInt::from(1_u64));
+            }
+        }
+        let largest = (2..=1000)
+            .filter_map(|d| solve_pell(d).map(|(x, _)| (x, d)))
+            .max()
+            .map(|(_, d)| d);
+        assert_eq!(largest, Some(661));
+    }
+
+    #[test]
+    fn solve_negative_pell_test() {
+        assert_eq!(
+            solve_negative_pell(2),
+            Some((BigUInt::from(1_u64), BigUInt::from(1_u64)))
+        );
+        assert_eq!(solve_negative_pell(4), None);
+        assert_eq!(solve_negative_pell(7), None);
+        for d in 2..200 {
+            if let Some(solution) = solve_negative_pell(d) {
+                let (x2, dy2) = pell_value(d, &solution);
+                assert_eq!(x2 + BigUInt::from(1_u64), dy2);
+            }
+        }
+    }
+
+    #[test]
+    fn pell_solutions_test() {
+        assert!(PellSolutions::new(9).is_none());
+        assert_eq!(
+            PellSolutions::new(2).unwrap().take(4).collect::<Vec<_>>(),
+            vec![
+                (BigUInt::from(3_u64), BigUInt::from(2_u64)),
+                (BigUInt::from(17_u64), BigUInt::from(12_u64)),
+                (BigUInt::from(99_u64), BigUInt::from(70_u64)),
+                (BigUInt::from(577_u64), BigUInt::from(408_u64)),
+            ]
+        );
+        for solution in PellSolutions::new(61).unwrap().take(5) {
+            let (x2, dy2) = pell_value(61, &solution);
+            assert_eq!(x2, dy2 + BigUInt::from(1_u64));
+        }
+    }
+}
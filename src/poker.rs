@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PokerCardSuit {
     Heart,
     Diamond,
@@ -6,184 +8,715 @@ pub enum PokerCardSuit {
     Spades,
 }
 
-#[derive(Debug, PartialEq)]
-pub struct PokerCards(PokerCardSuit, u8);
+impl PokerCardSuit {
+    /// All the suits, in the order they are sorted.
+    pub const ALL: [Self; 4] = [Self::Heart, Self::Diamond, Self::Clubs, Self::Spades];
+
+    /// Get the letter of the suit, as in `H`.
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Heart => 'H',
+            Self::Diamond => 'D',
+            Self::Clubs => 'C',
+            Self::Spades => 'S',
+        }
+    }
+
+    /// Get the Unicode symbol of the suit, as in `♥`.
+    pub fn symbol(&self) -> char {
+        match self {
+            Self::Heart => '♥',
+            Self::Diamond => '♦',
+            Self::Clubs => '♣',
+            Self::Spades => '♠',
+        }
+    }
+}
+
+/// Rank of a card, where the ace is high.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two = 2,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    /// All the ranks, from lowest to highest.
+    pub const ALL: [Self; 13] = [
+        Self::Two,
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Seven,
+        Self::Eight,
+        Self::Nine,
+        Self::Ten,
+        Self::Jack,
+        Self::Queen,
+        Self::King,
+        Self::Ace,
+    ];
+
+    /// Get the value of the rank, from 2 to 14 for the ace.
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Get the rank with the given value, `None` if it is not between 2 and 14.
+    pub fn from_value(value: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(value).checked_sub(2)?).copied()
+    }
+
+    /// Get the character of the rank, as in `T` for ten.
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Ten => 'T',
+            Self::Jack => 'J',
+            Self::Queen => 'Q',
+            Self::King => 'K',
+            Self::Ace => 'A',
+            _ => char::from(b'0' + self.value()),
+        }
+    }
+}
+
+/// Card of a standard 52-card deck.
+///
+/// Cards are sorted by suit, then by rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PokerCards(PokerCardSuit, Rank);
+
+impl PokerCards {
+    pub fn new(suit: PokerCardSuit, rank: Rank) -> Self {
+        Self(suit, rank)
+    }
+
+    pub fn suit(&self) -> PokerCardSuit {
+        self.0
+    }
+
+    pub fn rank(&self) -> Rank {
+        self.1
+    }
+}
+
+/// Writes the two-character notation, as in `TH`, or with the suit symbol, as in `T♥`, when
+/// using the alternate flag `{:#}`.
+impl Display for PokerCards {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let suit = if f.alternate() {
+            self.0.symbol()
+        } else {
+            self.0.letter()
+        };
+        write!(f, "{}{}", self.1.letter(), suit)
+    }
+}
+
+/// Error returned when parsing a `PokerCards` from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePokerCardsError {
+    /// The string did not have exactly two characters.
+    Length(usize),
+    /// The first character is not one of `23456789TJQKA`.
+    InvalidRank(char),
+    /// The second character is not one of `HDCS`.
+    InvalidSuit(char),
+}
+
+impl Display for ParsePokerCardsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Length(n) => write!(f, "A PokerCard has 2 characters, found {n}."),
+            Self::InvalidRank(c) => write!(f, "Not a known rank of PokerCard '{c}'."),
+            Self::InvalidSuit(c) => write!(f, "Not a known suit of PokerCard '{c}'."),
+        }
+    }
+}
+
+impl std::error::Error for ParsePokerCardsError {}
+
+impl FromStr for PokerCards {
+    type Err = ParsePokerCardsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s.chars().collect::<Vec<_>>();
+        let [rank, suit] = chars.as_slice() else {
+            return Err(ParsePokerCardsError::Length(chars.len()));
+        };
+        let rank = Rank::ALL
+            .into_iter()
+            .find(|r| r.letter() == *rank)
+            .ok_or(ParsePokerCardsError::InvalidRank(*rank))?;
+        let suit = PokerCardSuit::ALL
+            .into_iter()
+            .find(|s| s.letter() == *suit)
+            .ok_or(ParsePokerCardsError::InvalidSuit(*suit))?;
+        Ok(Self(suit, rank))
+    }
+}
 
 impl TryFrom<&str> for PokerCards {
-    type Error = String;
+    type Error = ParsePokerCardsError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let chars = value.chars().collect::<Vec<_>>();
-        let rank = match chars.as_slice() {
-            ['A', _] => 14,
-            ['K', _] => 13,
-            ['Q', _] => 12,
-            ['J', _] => 11,
-            ['T', _] => 10,
-            [a, _] => a.to_digit(10).unwrap() as u8,
-            _ => Err("Wrong number of character for a PokerCard.")?,
-        };
-        Ok(match chars.as_slice() {
-            [_, 'H'] => Self(PokerCardSuit::Heart, rank),
-            [_, 'D'] => Self(PokerCardSuit::Diamond, rank),
-            [_, 'C'] => Self(PokerCardSuit::Clubs, rank),
-            [_, 'S'] => Self(PokerCardSuit::Spades, rank),
-            _ => Err("Not a known suit of PokerCard.")?,
-        })
+        value.parse()
     }
 }
 
 /// Represents a hand in poker.
-#[derive(Debug, PartialEq, PartialOrd)]
+///
+/// Every variant holds all the ranks needed to break ties, so the derived ordering ranks any two
+/// hands the way the rules do, with equal hands being a split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PokerHand {
     /// Represents a hand with no special combination.
-    /// Holds the highest card on hand.
-    HighCard(u8),
+    /// Holds the ranks of all cards, highest first.
+    HighCard([Rank; 5]),
     /// Represents a pair of cards of same value.
     /// First value is the value of the pair,
-    /// the second is the highest card on hand.
-    OnePair(u8, u8),
+    /// the second is the remaining cards, highest first.
+    OnePair(Rank, [Rank; 3]),
     /// Represents two pair of cards of same value.
-    /// First value is the value of first the pair,
-    /// the second is the value of first the pair,
-    /// the third is the highest card on hand.
-    TwoPairs(u8, u8, u8),
+    /// First value is the value of the highest pair,
+    /// the second is the value of the lowest pair,
+    /// the third is the remaining card.
+    TwoPairs(Rank, Rank, Rank),
     /// Represents a triplet of cards of same value.
     /// First value is the value of the triplet,
-    /// the second  is the highest card on hand.
-    ThreeOfAKind(u8, u8),
+    /// the second is the remaining cards, highest first.
+    ThreeOfAKind(Rank, [Rank; 2]),
     /// Represents a sequence of cards.
-    /// First value is first value of the sequence,
-    /// the second  is the highest card on hand.
-    Straight(u8, u8),
+    /// Holds the highest card of the sequence.
+    Straight(Rank),
     /// Represents a hand with all cards of the same suit.
-    /// Holds the highest card on hand.
-    Flush(u8),
+    /// Holds the ranks of all cards, highest first.
+    Flush([Rank; 5]),
     /// Represents a triplet plus a pair.
     /// First value is the value of the triplet,
     /// the second is the value of the pair.
-    FullHouse(u8, u8),
+    FullHouse(Rank, Rank),
     /// Represents a quadruplet of cards of same value.
     /// First value is the value of the quadruplet,
-    /// the second is the highest card on hand.
-    FourOfAKind(u8, u8),
+    /// the second is the remaining card.
+    FourOfAKind(Rank, Rank),
     /// Represents a sequence of consecutive values and same suit.
-    /// First value is first value of the sequence,
-    /// the second is the highest card on hand.
-    StraightFlush(u8, u8),
+    /// Holds the highest card of the sequence.
+    StraightFlush(Rank),
     /// Represents a Straight Flush starting at 10.
     RoyalFlush,
 }
 
 impl PokerHand {
     pub fn new(cards: &[PokerCards]) -> Result<Self, String> {
-        match cards {
-            [PokerCards(sa, ra), PokerCards(sb, rb), PokerCards(sc, rc), PokerCards(sd, rd), PokerCards(se, re)]
-                if vec![sb, sc, sd, se].into_iter().all(|s| s == sa) =>
-            {
-                let mut values = vec![ra, rb, rc, rd, re];
-                values.sort();
-                let min = values[0];
-                let max = values[4];
-                if values.as_slice().windows(2).all(|wind| {
-                    if let [a, b] = wind {
-                        *a + 1 == **b
-                    } else {
-                        false
-                    }
-                }) {
-                    if min == &10 {
-                        Ok(Self::RoyalFlush)
-                    } else {
-                        Ok(Self::StraightFlush(*min, *max))
-                    }
-                } else {
-                    Ok(Self::Flush(*max))
+        if cards.len() != 5 {
+            Err("Hand had a number of cards different from 5.")?
+        }
+        let mut seen = HashSet::new();
+        if let Some(card) = cards.iter().find(|card| !seen.insert(*card)) {
+            Err(format!("Card {card} was dealt more than once."))?
+        }
+        let flush = cards.iter().all(|PokerCards(suit, _)| *suit == cards[0].0);
+
+        let mut ranks = cards.iter().map(|card| card.1).collect::<Vec<_>>();
+        ranks.sort_by(|a, b| b.cmp(a));
+        let mut groups: Vec<(usize, Rank)> = vec![];
+        for rank in &ranks {
+            match groups.last_mut() {
+                Some((count, r)) if r == rank => *count += 1,
+                _ => groups.push((1, *rank)),
+            }
+        }
+        groups.sort_by(|a, b| b.cmp(a));
+        let straight = match ranks.as_slice() {
+            // The wheel, where the ace plays low and the straight is 5-high.
+            [Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two] => Some(Rank::Five),
+            [high, .., low] if groups.len() == 5 && high.value() - low.value() == 4 => Some(*high),
+            _ => None,
+        };
+
+        Ok(match (groups.as_slice(), straight) {
+            (_, Some(Rank::Ace)) if flush => Self::RoyalFlush,
+            (_, Some(high)) if flush => Self::StraightFlush(high),
+            ([(4, quad), (1, kicker)], _) => Self::FourOfAKind(*quad, *kicker),
+            ([(3, triplet), (2, pair)], _) => Self::FullHouse(*triplet, *pair),
+            _ if flush => Self::Flush([ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]]),
+            (_, Some(high)) => Self::Straight(high),
+            ([(3, triplet), (1, a), (1, b)], _) => Self::ThreeOfAKind(*triplet, [*a, *b]),
+            ([(2, high), (2, low), (1, kicker)], _) => Self::TwoPairs(*high, *low, *kicker),
+            ([(2, pair), (1, a), (1, b), (1, c)], _) => Self::OnePair(*pair, [*a, *b, *c]),
+            (_, None) => Self::HighCard([ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]]),
+        })
+    }
+
+    /// Finds the best five-card hand out of 5 to 7 cards, as in Texas Hold'em, returning the hand
+    /// and the five cards used, in the order they are compared.
+    ///
+    /// Works on bitmasks of the ranks in each suit instead of trying every combination of five
+    /// cards.
+    pub fn best_of(cards: &[PokerCards]) -> Result<BestHand<'_>, String> {
+        if !(5..=7).contains(&cards.len()) {
+            Err("Best hand needs between 5 and 7 cards.")?
+        }
+        Ok(Self::evaluate(cards.iter())?.with_cards(cards.iter()))
+    }
+
+    /// Finds the best hand in Omaha, which must use exactly two of the four `hole` cards and
+    /// three of the five `board` cards.
+    pub fn best_omaha<'a>(
+        hole: &'a [PokerCards],
+        board: &'a [PokerCards],
+    ) -> Result<BestHand<'a>, String> {
+        if hole.len() != 4 || board.len() != 5 {
+            Err("Omaha needs 4 hole cards and 5 board cards.")?
+        }
+        let cards = |(a, b): (usize, usize), skipped: (usize, usize)| {
+            [&hole[a], &hole[b]].into_iter().chain(
+                board
+                    .iter()
+                    .enumerate()
+                    .filter(move |(i, _)| *i != skipped.0 && *i != skipped.1)
+                    .map(|(_, card)| card),
+            )
+        };
+        let mut best = None;
+        for used in (0..4).flat_map(|a| (a + 1..4).map(move |b| (a, b))) {
+            for skipped in (0..5).flat_map(|c| (c + 1..5).map(move |d| (c, d))) {
+                let candidate = Self::evaluate(cards(used, skipped))?;
+                if best.is_none_or(|(hand, _, _)| candidate > hand) {
+                    best = Some((candidate, used, skipped));
                 }
             }
-            [PokerCards(_, ra), PokerCards(_, rb), PokerCards(_, rc), PokerCards(_, rd), PokerCards(_, re)] =>
-            {
-                let mut ranks = vec![ra, rb, rc, rd, re];
-                ranks.sort();
-
-                Ok(match ranks.as_slice() {
-                    [a, b, c, d, e] | [e, a, b, c, d] if a == b && a == c && a == d => {
-                        Self::FourOfAKind(**a, **e)
-                    }
-                    [a, b, c, d, e] | [d, e, a, b, c] if a == b && a == c && d == e => {
-                        Self::FullHouse(**a, **d)
-                    }
-                    [a, b, c, d, e]
-                        if *a + 1 == **b && *b + 1 == **c && *c + 1 == **d && *d + 1 == **e =>
-                    {
-                        Self::Straight(**a, **e)
-                    }
-                    [a, b, c, _, max] | [_, a, b, c, max] | [_, max, a, b, c]
-                        if a == b && a == c =>
-                    {
-                        Self::ThreeOfAKind(**a, **max)
-                    }
-                    [a, b, c, d, max] | [a, b, max, c, d] | [max, a, b, c, d]
-                        if a == b && c == d =>
-                    {
-                        Self::TwoPairs(**a, **c, **max)
-                    }
-                    [a, b, _, _, max]
-                    | [_, a, b, _, max]
-                    | [_, _, a, b, max]
-                    | [_, _, max, a, b]
-                        if a == b =>
-                    {
-                        Self::OnePair(**a, **max)
-                    }
-                    [_, _, _, _, max] => Self::HighCard(**max),
-                    _ => panic!("Missing combination."),
-                })
+        }
+        best.map(|(hand, used, skipped)| hand.with_cards(cards(used, skipped)))
+            .ok_or_else(|| "No Omaha hand found.".into())
+    }
+
+    /// Evaluates the best five-card hand out of at least five cards, without finding which cards
+    /// make it.
+    ///
+    /// A card appearing twice is an error.
+    fn evaluate<'a, I>(cards: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a PokerCards>,
+    {
+        let mut suit_masks = [0_u16; 4];
+        let mut counts = [0_u8; 15];
+        for card @ PokerCards(suit, rank) in cards {
+            let mask = &mut suit_masks[suit_index(suit)];
+            if *mask & bit(*rank) != 0 {
+                Err(format!("Card {card} was dealt more than once."))?
             }
-            _ => Err("Hand had a number of cards different from 5.".into()),
+            *mask |= bit(*rank);
+            counts[usize::from(rank.value())] += 1;
+        }
+        let rank_mask = suit_masks.iter().fold(0, |mask, suit| mask | suit);
+        let with_count = |count: u8| {
+            (2..=14)
+                .filter(|rank| counts[*rank as usize] == count)
+                .fold(0_u16, |mask, rank| mask | 1 << rank)
+        };
+        let (quads, trips, pairs) = (with_count(4), with_count(3), with_count(2));
+        let flush_suit = (0..4).find(|suit| suit_masks[*suit].count_ones() >= 5);
+
+        Ok(
+            if let Some(high) = flush_suit.and_then(|suit| straight_high(suit_masks[suit])) {
+                if high == Rank::Ace {
+                    Self::RoyalFlush
+                } else {
+                    Self::StraightFlush(high)
+                }
+            } else if quads != 0 {
+                let quad = highest(quads);
+                Self::FourOfAKind(quad, highest(rank_mask & !bit(quad)))
+            } else if trips != 0 && (trips.count_ones() > 1 || pairs != 0) {
+                let triplet = highest(trips);
+                Self::FullHouse(triplet, highest((trips | pairs) & !bit(triplet)))
+            } else if let Some(suit) = flush_suit {
+                Self::Flush(top_ranks(suit_masks[suit]))
+            } else if let Some(high) = straight_high(rank_mask) {
+                Self::Straight(high)
+            } else if trips != 0 {
+                let triplet = highest(trips);
+                Self::ThreeOfAKind(triplet, top_ranks(rank_mask & !bit(triplet)))
+            } else if pairs.count_ones() >= 2 {
+                let high = highest(pairs);
+                let low = highest(pairs & !bit(high));
+                Self::TwoPairs(high, low, highest(rank_mask & !(bit(high) | bit(low))))
+            } else if pairs != 0 {
+                let pair = highest(pairs);
+                Self::OnePair(pair, top_ranks(rank_mask & !bit(pair)))
+            } else {
+                Self::HighCard(top_ranks(rank_mask))
+            },
+        )
+    }
+
+    /// Picks the cards that make the hand out of the cards it was evaluated from.
+    fn with_cards<'a, I>(self, cards: I) -> BestHand<'a>
+    where
+        I: Iterator<Item = &'a PokerCards> + Clone,
+    {
+        let suit = matches!(
+            self,
+            Self::RoyalFlush | Self::StraightFlush(_) | Self::Flush(_)
+        )
+        .then(|| {
+            (0..4).find(|suit| {
+                cards
+                    .clone()
+                    .filter(|PokerCards(s, _)| suit_index(s) == *suit)
+                    .count()
+                    >= 5
+            })
+        })
+        .flatten();
+        (self, pick_cards(cards, &self.used_ranks(), suit))
+    }
+
+    /// Get the ranks that make the hand, with how many cards of each rank are used.
+    fn used_ranks(&self) -> Vec<(Rank, usize)> {
+        // Below the two comes the ace, playing low in the wheel.
+        let straight = |high: Rank| {
+            (0..5)
+                .map(|i| (Rank::from_value(high.value() - i).unwrap_or(Rank::Ace), 1))
+                .collect()
+        };
+        match *self {
+            Self::HighCard(ranks) | Self::Flush(ranks) => ranks.map(|r| (r, 1)).to_vec(),
+            Self::OnePair(pair, [a, b, c]) => vec![(pair, 2), (a, 1), (b, 1), (c, 1)],
+            Self::TwoPairs(high, low, kicker) => vec![(high, 2), (low, 2), (kicker, 1)],
+            Self::ThreeOfAKind(triplet, [a, b]) => vec![(triplet, 3), (a, 1), (b, 1)],
+            Self::Straight(high) | Self::StraightFlush(high) => straight(high),
+            Self::FullHouse(triplet, pair) => vec![(triplet, 3), (pair, 2)],
+            Self::FourOfAKind(quad, kicker) => vec![(quad, 4), (kicker, 1)],
+            Self::RoyalFlush => straight(Rank::Ace),
+        }
+    }
+}
+
+/// Best five-card hand found by [`PokerHand::best_of`], with the cards that make it.
+pub type BestHand<'a> = (PokerHand, [&'a PokerCards; 5]);
+
+/// Get the position of the suit in the per-suit masks.
+fn suit_index(suit: &PokerCardSuit) -> usize {
+    match suit {
+        PokerCardSuit::Heart => 0,
+        PokerCardSuit::Diamond => 1,
+        PokerCardSuit::Clubs => 2,
+        PokerCardSuit::Spades => 3,
+    }
+}
+
+/// Get the bit of a rank in a mask.
+fn bit(rank: Rank) -> u16 {
+    1 << rank.value()
+}
+
+/// Get the highest rank in a mask.
+///
+/// # Panic
+/// Panics if the mask has no rank.
+fn highest(mask: u16) -> Rank {
+    Rank::from_value((15 - mask.leading_zeros()) as u8).expect("No rank in the mask.")
+}
+
+/// Get the `N` highest ranks in a mask.
+fn top_ranks<const N: usize>(mut mask: u16) -> [Rank; N] {
+    [0; N].map(|_| {
+        let rank = highest(mask);
+        mask &= !bit(rank);
+        rank
+    })
+}
+
+/// Get the highest card of the best straight in a mask of ranks, where the ace can also play low.
+fn straight_high(mask: u16) -> Option<Rank> {
+    let mask = mask | (mask >> 14 & 1) << 1;
+    let runs = mask & mask << 1 & mask << 2 & mask << 3 & mask << 4;
+    (runs != 0).then(|| highest(runs))
+}
+
+/// Picks the cards that make a hand out of `cards`, following the order of `ranks`.
+fn pick_cards<'a, I>(cards: I, ranks: &[(Rank, usize)], suit: Option<usize>) -> [&'a PokerCards; 5]
+where
+    I: Iterator<Item = &'a PokerCards> + Clone,
+{
+    let mut picked = [cards.clone().next().expect("Hand has no cards."); 5];
+    let mut i = 0;
+    for (rank, count) in ranks {
+        for card in cards
+            .clone()
+            .filter(|PokerCards(s, r)| r == rank && suit.is_none_or(|suit| suit == suit_index(s)))
+            .take(*count)
+        {
+            picked[i] = card;
+            i += 1;
         }
     }
+    picked
+}
+
+/// Outcome of a game of poker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameResult {
+    /// Player 1 has the best hand.
+    Player1,
+    /// Player 2 has the best hand.
+    Player2,
+    /// Both hands are equal and the pot is split.
+    Split,
 }
 
 pub struct Poker;
 
 impl Poker {
-    /// Resolves the result of a game, `true` if Player 1 wins, `false` otherwise.
+    /// Resolves the result of a game.
     ///
     /// A game is represented by a String containing 10 cards, the first five represent Player 1's hand.
-    pub fn resolve_game(game: &str) -> Result<bool, String> {
-        let split: (Vec<_>, Vec<_>) = game
+    /// A card appearing twice is an error.
+    pub fn resolve_game(game: &str) -> Result<GameResult, String> {
+        let cards = game
             .split_whitespace()
-            .enumerate()
-            .partition(|(i, _)| i < &5);
-        let hand1 = PokerHand::new(
-            &split
-                .0
-                .into_iter()
-                .map(|(_, card)| PokerCards::try_from(card))
-                .collect::<Result<Vec<_>, String>>()?,
-        )?;
-        let hand2 = PokerHand::new(
-            &split
-                .1
-                .into_iter()
-                .map(|(_, card)| PokerCards::try_from(card))
-                .collect::<Result<Vec<_>, String>>()?,
-        )?;
-        Ok(hand1 > hand2)
+            .map(|card| card.parse::<PokerCards>().map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, String>>()?;
+        let mut seen = HashSet::new();
+        if let Some(card) = cards.iter().find(|card| !seen.insert(*card)) {
+            Err(format!("Card {card} was dealt more than once."))?
+        }
+        let (cards1, cards2) = cards.split_at(cards.len().min(5));
+        let hand1 = PokerHand::new(cards1)?;
+        let hand2 = PokerHand::new(cards2)?;
+        Ok(match hand1.cmp(&hand2) {
+            std::cmp::Ordering::Greater => GameResult::Player1,
+            std::cmp::Ordering::Less => GameResult::Player2,
+            std::cmp::Ordering::Equal => GameResult::Split,
+        })
     }
 
-    /// Resolves the result of a tournament, returning a tuple with how many games Player 1 and Player 2 won.
-    pub fn resolve_tournament(tournament: &str) -> Result<(u64, u64), String> {
+    /// Resolves the result of a tournament, returning a tuple with how many games Player 1 won,
+    /// how many Player 2 won, and how many were split.
+    pub fn resolve_tournament(tournament: &str) -> Result<(u64, u64, u64), String> {
         Ok(tournament
             .lines()
             .map(Self::resolve_game)
             .collect::<Result<Vec<_>, String>>()?
             .into_iter()
-            .fold(
-                (0, 0),
-                |(p1, p2), cur| if cur { (p1 + 1, p2) } else { (p1, p2 + 1) },
-            ))
+            .fold((0, 0, 0), |(p1, p2, split), cur| match cur {
+                GameResult::Player1 => (p1 + 1, p2, split),
+                GameResult::Player2 => (p1, p2 + 1, split),
+                GameResult::Split => (p1, p2, split + 1),
+            }))
+    }
+
+    /// Calculates the probabilities of each player winning or splitting a game of Texas Hold'em,
+    /// given their `hole` cards and the `board` cards already dealt.
+    ///
+    /// The missing board cards are either all enumerated or sampled, according to `method`.
+    pub fn equity(
+        hole: &[[PokerCards; 2]],
+        board: &[PokerCards],
+        method: EquityMethod,
+    ) -> Result<Vec<Equity>, String> {
+        if hole.is_empty() {
+            Err("Equity needs at least one player.")?
+        } else if board.len() > 5 {
+            Err("Board can't have more than 5 cards.")?
+        } else if 2 * hole.len() + 5 > 52 {
+            // The hole cards and a full board must all come from the same deck.
+            Err(format!("Not enough cards for {} players.", hole.len()))?
+        } else if matches!(method, EquityMethod::MonteCarlo { trials: 0, .. }) {
+            Err("Monte Carlo needs at least one trial.")?
+        }
+        let mut deck = Deck::new();
+        for card in hole.iter().flatten().chain(board) {
+            if !deck.remove(card) {
+                Err(format!("Card {card} was dealt more than once."))?
+            }
+        }
+        let missing = 5 - board.len();
+
+        let mut wins = vec![0_u64; hole.len()];
+        let mut ties = vec![0_u64; hole.len()];
+        let mut showdown = |drawn: &[usize]| -> Result<(), String> {
+            let hands = hole
+                .iter()
+                .map(|[a, b]| {
+                    let cards = [a, b]
+                        .into_iter()
+                        .chain(board)
+                        .chain(drawn.iter().map(|i| &deck.cards[*i]));
+                    PokerHand::evaluate(cards)
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            let best = hands.iter().max().unwrap();
+            let winners = hands.iter().filter(|hand| *hand == best).count();
+            for (i, _) in hands.iter().enumerate().filter(|(_, hand)| *hand == best) {
+                if winners == 1 {
+                    wins[i] += 1;
+                } else {
+                    ties[i] += 1;
+                }
+            }
+            Ok(())
+        };
+
+        let total = match method {
+            EquityMethod::Exhaustive => {
+                let mut drawn = (0..missing).collect::<Vec<_>>();
+                let mut total = 0;
+                loop {
+                    showdown(&drawn)?;
+                    total += 1;
+                    // Advance to the next combination in lexicographic order.
+                    let Some(i) = (0..missing)
+                        .rev()
+                        .find(|i| drawn[*i] < deck.cards.len() - missing + i)
+                    else {
+                        break;
+                    };
+                    drawn[i] += 1;
+                    for j in i + 1..missing {
+                        drawn[j] = drawn[j - 1] + 1;
+                    }
+                }
+                total
+            }
+            EquityMethod::MonteCarlo { trials, seed } => {
+                let mut rng = SplitMix64(seed);
+                let mut indices = (0..deck.cards.len()).collect::<Vec<_>>();
+                for _ in 0..trials {
+                    for i in 0..missing {
+                        let j = i + rng.below(indices.len() - i);
+                        indices.swap(i, j);
+                    }
+                    showdown(&indices[..missing])?;
+                }
+                trials
+            }
+        };
+
+        Ok(wins
+            .into_iter()
+            .zip(ties)
+            .map(|(win, tie)| Equity {
+                win: win as f64 / total as f64,
+                tie: tie as f64 / total as f64,
+            })
+            .collect())
+    }
+}
+
+/// How [`Poker::equity`] deals the missing board cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquityMethod {
+    /// Enumerates every possible board.
+    Exhaustive,
+    /// Samples `trials` random boards, reproducibly for the same `seed`.
+    MonteCarlo { trials: u64, seed: u64 },
+}
+
+/// Probabilities of a player winning alone or splitting the pot.
+#[derive(Debug, Clone, Copy)]
+pub struct Equity {
+    pub win: f64,
+    pub tie: f64,
+}
+
+/// Deck of the 52 poker cards.
+#[derive(Debug, PartialEq)]
+pub struct Deck {
+    cards: Vec<PokerCards>,
+}
+
+impl Deck {
+    /// Create a full deck, sorted by suit and then by rank.
+    pub fn new() -> Self {
+        Self {
+            cards: PokerCardSuit::ALL
+                .into_iter()
+                .flat_map(|suit| Rank::ALL.map(|rank| PokerCards(suit, rank)))
+                .collect(),
+        }
+    }
+
+    /// Get the cards left in the deck, the top card being the last.
+    pub fn cards(&self) -> &[PokerCards] {
+        &self.cards
+    }
+
+    /// Get the number of cards left in the deck.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Checks if there are no cards left in the deck.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Shuffles the deck with a Fisher-Yates shuffle, the same `seed` always gives the same order.
+    pub fn shuffle(&mut self, seed: u64) {
+        let mut rng = SplitMix64(seed);
+        for i in (1..self.cards.len()).rev() {
+            let j = rng.below(i + 1);
+            self.cards.swap(i, j);
+        }
+    }
+
+    /// Deals `count` cards from the top of the deck, `None` if there are not enough cards.
+    pub fn deal(&mut self, count: usize) -> Option<Vec<PokerCards>> {
+        let rest = self.cards.len().checked_sub(count)?;
+        Some(self.cards.split_off(rest).into_iter().rev().collect())
+    }
+
+    /// Removes a known card from the deck, `false` if it was not in the deck.
+    ///
+    /// The top card takes the place of the removed one.
+    pub fn remove(&mut self, card: &PokerCards) -> bool {
+        match self.cards.iter().position(|c| c == card) {
+            Some(i) => {
+                self.cards.swap_remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SplitMix64 pseudorandom number generator, small and good enough for shuffling.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Get a number in `0..bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        ((u128::from(self.next()) * bound as u128) >> 64) as usize
     }
 }
 
@@ -196,148 +729,354 @@ mod test {
         assert!(PokerCards::try_from("AB").is_err());
         assert_eq!(
             PokerCards::try_from("TC"),
-            Ok(PokerCards(PokerCardSuit::Clubs, 10))
+            Ok(PokerCards(PokerCardSuit::Clubs, Rank::Ten))
         );
         assert_eq!(
             PokerCards::try_from("AD"),
-            Ok(PokerCards(PokerCardSuit::Diamond, 14))
+            Ok(PokerCards(PokerCardSuit::Diamond, Rank::Ace))
         );
         assert_eq!(
             PokerCards::try_from("KS"),
-            Ok(PokerCards(PokerCardSuit::Spades, 13))
+            Ok(PokerCards(PokerCardSuit::Spades, Rank::King))
         );
         assert_eq!(
             PokerCards::try_from("2H"),
-            Ok(PokerCards(PokerCardSuit::Heart, 2))
+            Ok(PokerCards(PokerCardSuit::Heart, Rank::Two))
+        );
+        assert_eq!(
+            PokerCards::try_from("XH"),
+            Err(ParsePokerCardsError::InvalidRank('X'))
+        );
+        assert_eq!(
+            "1H".parse::<PokerCards>(),
+            Err(ParsePokerCardsError::InvalidRank('1'))
+        );
+        assert_eq!(
+            "0H".parse::<PokerCards>(),
+            Err(ParsePokerCardsError::InvalidRank('0'))
+        );
+        assert_eq!(
+            "10H".parse::<PokerCards>(),
+            Err(ParsePokerCardsError::Length(3))
+        );
+        assert_eq!(
+            "AB".parse::<PokerCards>(),
+            Err(ParsePokerCardsError::InvalidSuit('B'))
         );
     }
 
+    #[test]
+    fn card_display_test() {
+        for card in Deck::new().cards() {
+            assert_eq!(card.to_string().parse(), Ok(*card));
+        }
+        let card = PokerCards::new(PokerCardSuit::Spades, Rank::Ten);
+        assert_eq!(card.to_string(), "TS");
+        assert_eq!(format!("{card:#}"), "T♠");
+    }
+
+    #[test]
+    fn card_accessors_test() {
+        let card = PokerCards::new(PokerCardSuit::Spades, Rank::Ten);
+        assert_eq!(card.suit(), PokerCardSuit::Spades);
+        assert_eq!(card.rank(), Rank::Ten);
+    }
+
+    #[test]
+    fn rank_value_test() {
+        assert_eq!(Rank::Ten.value(), 10);
+        assert_eq!(Rank::from_value(10), Some(Rank::Ten));
+        assert_eq!(Rank::from_value(1), None);
+        assert_eq!(Rank::from_value(15), None);
+        for rank in Rank::ALL {
+            assert_eq!(Rank::from_value(rank.value()), Some(rank));
+        }
+    }
+
+    #[test]
+    fn card_order_test() {
+        assert!(Rank::Ace > Rank::King);
+        let deck = Deck::new();
+        assert!(deck.cards().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
     #[test]
     fn poker_hand_test() {
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Heart, 5),
-                PokerCards(PokerCardSuit::Clubs, 5),
-                PokerCards(PokerCardSuit::Spades, 6),
-                PokerCards(PokerCardSuit::Spades, 7),
-                PokerCards(PokerCardSuit::Diamond, 13)
+                PokerCards(PokerCardSuit::Heart, Rank::Five),
+                PokerCards(PokerCardSuit::Clubs, Rank::Five),
+                PokerCards(PokerCardSuit::Spades, Rank::Six),
+                PokerCards(PokerCardSuit::Spades, Rank::Seven),
+                PokerCards(PokerCardSuit::Diamond, Rank::King)
+            ]),
+            Ok(PokerHand::OnePair(
+                Rank::Five,
+                [Rank::King, Rank::Seven, Rank::Six]
+            ))
+        );
+        assert_eq!(
+            PokerHand::new(&[
+                PokerCards(PokerCardSuit::Clubs, Rank::Two),
+                PokerCards(PokerCardSuit::Spades, Rank::Three),
+                PokerCards(PokerCardSuit::Spades, Rank::Eight),
+                PokerCards(PokerCardSuit::Diamond, Rank::Eight),
+                PokerCards(PokerCardSuit::Diamond, Rank::Ten)
+            ]),
+            Ok(PokerHand::OnePair(
+                Rank::Eight,
+                [Rank::Ten, Rank::Three, Rank::Two]
+            ))
+        );
+        assert_eq!(
+            PokerHand::new(&[
+                PokerCards(PokerCardSuit::Diamond, Rank::Five),
+                PokerCards(PokerCardSuit::Clubs, Rank::Eight),
+                PokerCards(PokerCardSuit::Spades, Rank::Nine),
+                PokerCards(PokerCardSuit::Spades, Rank::Jack),
+                PokerCards(PokerCardSuit::Clubs, Rank::Ace)
+            ]),
+            Ok(PokerHand::HighCard([
+                Rank::Ace,
+                Rank::Jack,
+                Rank::Nine,
+                Rank::Eight,
+                Rank::Five
+            ]))
+        );
+        assert_eq!(
+            PokerHand::new(&[
+                PokerCards(PokerCardSuit::Clubs, Rank::Two),
+                PokerCards(PokerCardSuit::Clubs, Rank::Five),
+                PokerCards(PokerCardSuit::Diamond, Rank::Seven),
+                PokerCards(PokerCardSuit::Spades, Rank::Eight),
+                PokerCards(PokerCardSuit::Heart, Rank::Queen)
             ]),
-            Ok(PokerHand::OnePair(5, 13))
+            Ok(PokerHand::HighCard([
+                Rank::Queen,
+                Rank::Eight,
+                Rank::Seven,
+                Rank::Five,
+                Rank::Two
+            ]))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Clubs, 2),
-                PokerCards(PokerCardSuit::Spades, 3),
-                PokerCards(PokerCardSuit::Spades, 8),
-                PokerCards(PokerCardSuit::Diamond, 8),
-                PokerCards(PokerCardSuit::Diamond, 10)
+                PokerCards(PokerCardSuit::Diamond, Rank::Two),
+                PokerCards(PokerCardSuit::Clubs, Rank::Nine),
+                PokerCards(PokerCardSuit::Spades, Rank::Ace),
+                PokerCards(PokerCardSuit::Heart, Rank::Ace),
+                PokerCards(PokerCardSuit::Clubs, Rank::Ace)
             ]),
-            Ok(PokerHand::OnePair(8, 10))
+            Ok(PokerHand::ThreeOfAKind(Rank::Ace, [Rank::Nine, Rank::Two]))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Diamond, 5),
-                PokerCards(PokerCardSuit::Clubs, 8),
-                PokerCards(PokerCardSuit::Spades, 9),
-                PokerCards(PokerCardSuit::Spades, 11),
-                PokerCards(PokerCardSuit::Clubs, 14)
+                PokerCards(PokerCardSuit::Diamond, Rank::Three),
+                PokerCards(PokerCardSuit::Diamond, Rank::Six),
+                PokerCards(PokerCardSuit::Diamond, Rank::Seven),
+                PokerCards(PokerCardSuit::Diamond, Rank::Ten),
+                PokerCards(PokerCardSuit::Diamond, Rank::Queen)
             ]),
-            Ok(PokerHand::HighCard(14))
+            Ok(PokerHand::Flush([
+                Rank::Queen,
+                Rank::Ten,
+                Rank::Seven,
+                Rank::Six,
+                Rank::Three
+            ]))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Clubs, 2),
-                PokerCards(PokerCardSuit::Clubs, 5),
-                PokerCards(PokerCardSuit::Diamond, 7),
-                PokerCards(PokerCardSuit::Spades, 8),
-                PokerCards(PokerCardSuit::Heart, 12)
+                PokerCards(PokerCardSuit::Diamond, Rank::Four),
+                PokerCards(PokerCardSuit::Spades, Rank::Six),
+                PokerCards(PokerCardSuit::Heart, Rank::Nine),
+                PokerCards(PokerCardSuit::Heart, Rank::Queen),
+                PokerCards(PokerCardSuit::Clubs, Rank::Queen)
             ]),
-            Ok(PokerHand::HighCard(12))
+            Ok(PokerHand::OnePair(
+                Rank::Queen,
+                [Rank::Nine, Rank::Six, Rank::Four]
+            ))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Diamond, 2),
-                PokerCards(PokerCardSuit::Clubs, 9),
-                PokerCards(PokerCardSuit::Spades, 14),
-                PokerCards(PokerCardSuit::Heart, 14),
-                PokerCards(PokerCardSuit::Clubs, 14)
+                PokerCards(PokerCardSuit::Diamond, Rank::Three),
+                PokerCards(PokerCardSuit::Diamond, Rank::Six),
+                PokerCards(PokerCardSuit::Heart, Rank::Seven),
+                PokerCards(PokerCardSuit::Diamond, Rank::Queen),
+                PokerCards(PokerCardSuit::Spades, Rank::Queen)
             ]),
-            Ok(PokerHand::ThreeOfAKind(14, 9))
+            Ok(PokerHand::OnePair(
+                Rank::Queen,
+                [Rank::Seven, Rank::Six, Rank::Three]
+            ))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Diamond, 3),
-                PokerCards(PokerCardSuit::Diamond, 6),
-                PokerCards(PokerCardSuit::Diamond, 7),
-                PokerCards(PokerCardSuit::Diamond, 10),
-                PokerCards(PokerCardSuit::Diamond, 12)
+                PokerCards(PokerCardSuit::Heart, Rank::Two),
+                PokerCards(PokerCardSuit::Diamond, Rank::Two),
+                PokerCards(PokerCardSuit::Clubs, Rank::Four),
+                PokerCards(PokerCardSuit::Diamond, Rank::Four),
+                PokerCards(PokerCardSuit::Spades, Rank::Four)
             ]),
-            Ok(PokerHand::Flush(12))
+            Ok(PokerHand::FullHouse(Rank::Four, Rank::Two))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Diamond, 4),
-                PokerCards(PokerCardSuit::Spades, 6),
-                PokerCards(PokerCardSuit::Heart, 9),
-                PokerCards(PokerCardSuit::Heart, 12),
-                PokerCards(PokerCardSuit::Clubs, 12)
+                PokerCards(PokerCardSuit::Clubs, Rank::Three),
+                PokerCards(PokerCardSuit::Diamond, Rank::Three),
+                PokerCards(PokerCardSuit::Spades, Rank::Three),
+                PokerCards(PokerCardSuit::Spades, Rank::Nine),
+                PokerCards(PokerCardSuit::Diamond, Rank::Nine)
             ]),
-            Ok(PokerHand::OnePair(12, 9))
+            Ok(PokerHand::FullHouse(Rank::Three, Rank::Nine))
         );
+        assert_eq!(
+            PokerHand::new(&parse("AH AH AD AD AC")),
+            Err("Card AH was dealt more than once.".to_string())
+        );
+    }
+
+    #[test]
+    fn wheel_test() {
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Diamond, 3),
-                PokerCards(PokerCardSuit::Diamond, 6),
-                PokerCards(PokerCardSuit::Heart, 7),
-                PokerCards(PokerCardSuit::Diamond, 12),
-                PokerCards(PokerCardSuit::Spades, 12)
+                PokerCards(PokerCardSuit::Heart, Rank::Ace),
+                PokerCards(PokerCardSuit::Clubs, Rank::Two),
+                PokerCards(PokerCardSuit::Spades, Rank::Three),
+                PokerCards(PokerCardSuit::Spades, Rank::Four),
+                PokerCards(PokerCardSuit::Diamond, Rank::Five)
             ]),
-            Ok(PokerHand::OnePair(12, 7))
+            Ok(PokerHand::Straight(Rank::Five))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Heart, 2),
-                PokerCards(PokerCardSuit::Diamond, 2),
-                PokerCards(PokerCardSuit::Clubs, 4),
-                PokerCards(PokerCardSuit::Diamond, 4),
-                PokerCards(PokerCardSuit::Spades, 4)
+                PokerCards(PokerCardSuit::Diamond, Rank::Five),
+                PokerCards(PokerCardSuit::Diamond, Rank::Four),
+                PokerCards(PokerCardSuit::Diamond, Rank::Three),
+                PokerCards(PokerCardSuit::Diamond, Rank::Two),
+                PokerCards(PokerCardSuit::Diamond, Rank::Ace)
             ]),
-            Ok(PokerHand::FullHouse(4, 2))
+            Ok(PokerHand::StraightFlush(Rank::Five))
         );
         assert_eq!(
             PokerHand::new(&[
-                PokerCards(PokerCardSuit::Clubs, 3),
-                PokerCards(PokerCardSuit::Diamond, 3),
-                PokerCards(PokerCardSuit::Spades, 3),
-                PokerCards(PokerCardSuit::Spades, 9),
-                PokerCards(PokerCardSuit::Diamond, 9)
+                PokerCards(PokerCardSuit::Heart, Rank::Ace),
+                PokerCards(PokerCardSuit::Clubs, Rank::King),
+                PokerCards(PokerCardSuit::Spades, Rank::Two),
+                PokerCards(PokerCardSuit::Spades, Rank::Three),
+                PokerCards(PokerCardSuit::Diamond, Rank::Four)
             ]),
-            Ok(PokerHand::FullHouse(3, 9))
+            Ok(PokerHand::HighCard([
+                Rank::Ace,
+                Rank::King,
+                Rank::Four,
+                Rank::Three,
+                Rank::Two
+            ]))
         );
+        assert_eq!(
+            Poker::resolve_game("AH 2C 3S 4S 5D 2H 3C 4D 5S 6H"),
+            Ok(GameResult::Player2)
+        );
+        assert_eq!(
+            Poker::resolve_game("AH 2C 3S 4S 5D KH KC KD 9S 9H"),
+            Ok(GameResult::Player2)
+        );
+        assert_eq!(
+            Poker::resolve_game("AH 2C 3S 4S 5D AS AC AD 9S 8H"),
+            Ok(GameResult::Player1)
+        );
+        assert_eq!(
+            Poker::resolve_game("AD 2D 3D 4D 5D KH KC KD KS 9H"),
+            Ok(GameResult::Player1)
+        );
+        assert_eq!(
+            Poker::resolve_game("AD 2D 3D 4D 5D 2H 3H 4H 5H 6H"),
+            Ok(GameResult::Player2)
+        );
+    }
+
+    #[test]
+    fn poker_hand_order_test() {
+        let hand = |cards: &str| PokerHand::new(&parse(cards)).unwrap();
+        let ascending = [
+            "2C 3D 4H 5S 7C",
+            "2D 3H 4S 6C 7D",
+            "AC KD QH JS 9C",
+            "2C 2D 3H 4S 5C",
+            "2C 2D 3H 4S 6C",
+            "2C 2D 5H 4S 6C",
+            "3C 3D 2H 4S 5C",
+            "2C 2D 3H 3S 4C",
+            "2C 2D 3H 3S 5C",
+            "2C 2D 4H 4S 3C",
+            "2C 2D 2H 3S 4C",
+            "2C 2D 2H 3S 5C",
+            "3C 3D 3H 2S 4C",
+            "AC 2D 3H 4S 5C",
+            "2C 3D 4H 5S 6C",
+            "3C 4D 5H 6S 7C",
+            "TC JD QH KS AC",
+            "2C 3C 4C 5C 7C",
+            "2C 3C 4C 6C 7C",
+            "2C 2D 2H 3S 3C",
+            "2C 2D 2H 4S 4C",
+            "3C 3D 3H 2S 2C",
+            "2C 2D 2H 2S 3C",
+            "2C 2D 2H 2S 4C",
+            "3C 3D 3H 3S 2C",
+            "AC 2C 3C 4C 5C",
+            "2C 3C 4C 5C 6C",
+            "9C TC JC QC KC",
+            "TC JC QC KC AC",
+        ];
+        for pair in ascending.windows(2) {
+            assert!(hand(pair[0]) < hand(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+        assert_eq!(hand("2C 3D 4H 5S 7C"), hand("2D 3H 4S 5C 7H"));
     }
 
     #[test]
     fn resolve_game_test() {
         assert_eq!(
             Poker::resolve_game("5H 5C 6S 7S KD 2C 3S 8S 8D TD"),
-            Ok(false)
+            Ok(GameResult::Player2)
         );
         assert_eq!(
             Poker::resolve_game("5D 8C 9S JS AC 2C 5C 7D 8S QH"),
-            Ok(true)
+            Ok(GameResult::Player1)
         );
         assert_eq!(
             Poker::resolve_game("2D 9C AS AH AC 3D 6D 7D TD QD"),
-            Ok(false)
+            Ok(GameResult::Player2)
         );
         assert_eq!(
             Poker::resolve_game("4D 6S 9H QH QC 3D 6D 7H QD QS"),
-            Ok(true)
+            Ok(GameResult::Player1)
         );
         assert_eq!(
             Poker::resolve_game("2H 2D 4C 4D 4S 3C 3D 3S 9S 9D"),
-            Ok(true)
+            Ok(GameResult::Player1)
+        );
+        assert_eq!(
+            Poker::resolve_game("AH 9D 7C 5S 3H AD 9C 7S 5H 2D"),
+            Ok(GameResult::Player1)
+        );
+        assert_eq!(
+            Poker::resolve_game("AH 9D 7C 5S 3H AD 9C 7S 5H 3D"),
+            Ok(GameResult::Split)
         );
+        assert_eq!(
+            Poker::resolve_game("KH KD 7C 5S 3H KC KS 7S 5H 4D"),
+            Ok(GameResult::Player2)
+        );
+        assert_eq!(
+            Poker::resolve_game("AH 9D 7C 5S 3H AH 9C 7S 5H 3D"),
+            Err("Card AH was dealt more than once.".to_string())
+        );
+        assert!(Poker::resolve_game("AH 9D 7C 5S 3H AD 9C 7S 5H 1D").is_err());
     }
 
     #[test]
@@ -347,6 +1086,189 @@ mod test {
         2D 9C AS AH AC 3D 6D 7D TD QD
         4D 6S 9H QH QC 3D 6D 7H QD QS
         2H 2D 4C 4D 4S 3C 3D 3S 9S 9D";
-        assert_eq!(Poker::resolve_tournament(tournament), Ok((3, 2)));
+        assert_eq!(Poker::resolve_tournament(tournament), Ok((3, 2, 0)));
+    }
+
+    fn parse(cards: &str) -> Vec<PokerCards> {
+        cards
+            .split_whitespace()
+            .map(PokerCards::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn best_of_seven_test() {
+        for seed in 0..2000 {
+            let mut deck = Deck::new();
+            deck.shuffle(seed);
+            let hand = deck.deal(7).unwrap();
+            let (best, used) = PokerHand::best_of(&hand).unwrap();
+
+            let brute_force = (0..7)
+                .flat_map(|a| (a + 1..7).map(move |b| (a, b)))
+                .map(|(a, b)| {
+                    let five = (0..7)
+                        .filter(|i| *i != a && *i != b)
+                        .map(|i| hand[i])
+                        .collect::<Vec<_>>();
+                    PokerHand::new(&five).unwrap()
+                })
+                .max()
+                .unwrap();
+            assert_eq!(best, brute_force);
+
+            assert!(used.iter().all(|card| hand.contains(card)));
+            assert_eq!(PokerHand::new(&used.map(|card| *card)), Ok(best));
+        }
+    }
+
+    #[test]
+    fn best_of_test() {
+        let hand = parse("AH 2H 3H 4H 5H 6C 7D");
+        let (best, used) = PokerHand::best_of(&hand).unwrap();
+        assert_eq!(best, PokerHand::StraightFlush(Rank::Five));
+        assert_eq!(
+            used.map(|c| c.rank()),
+            [Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+        );
+
+        let hand = parse("2C 3C 4H 5S 6C 9C QC");
+        assert_eq!(
+            PokerHand::best_of(&hand).unwrap().0,
+            PokerHand::Flush([Rank::Queen, Rank::Nine, Rank::Six, Rank::Three, Rank::Two])
+        );
+        let hand = parse("9C 9D 9H 5S 5C 5D 2C");
+        let (best, used) = PokerHand::best_of(&hand).unwrap();
+        assert_eq!(best, PokerHand::FullHouse(Rank::Nine, Rank::Five));
+        assert_eq!(
+            used.map(|c| c.rank()),
+            [Rank::Nine, Rank::Nine, Rank::Nine, Rank::Five, Rank::Five]
+        );
+        let hand = parse("9C 9D 4H 4S 5C 5D 2C");
+        assert_eq!(
+            PokerHand::best_of(&hand).unwrap().0,
+            PokerHand::TwoPairs(Rank::Nine, Rank::Five, Rank::Four)
+        );
+        let hand = parse("TH JH QH KH AH 9H 8H");
+        assert_eq!(PokerHand::best_of(&hand).unwrap().0, PokerHand::RoyalFlush);
+        assert!(PokerHand::best_of(&parse("2C 3D 4H 5S")).is_err());
+        assert!(PokerHand::best_of(&parse("2C 3D 4H 5S 6C 7C 8C 9C")).is_err());
+
+        assert_eq!(
+            PokerHand::best_of(&parse("AH AH AD AD AC")),
+            Err("Card AH was dealt more than once.".to_string())
+        );
+        assert!(PokerHand::best_omaha(&parse("2C 3D 4H 5S"), &parse("2C 7D 8H 9S TC")).is_err());
+    }
+
+    #[test]
+    fn best_omaha_test() {
+        let hole = parse("AH AD KH KD");
+        let board = parse("QH JH TH 2C 3C");
+        let (best, used) = PokerHand::best_omaha(&hole, &board).unwrap();
+        assert_eq!(best, PokerHand::RoyalFlush);
+        assert_eq!(
+            used.map(|c| c.rank()),
+            [Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten]
+        );
+
+        // Four hearts on the board and a single heart in hand is not a flush in Omaha.
+        let hole = parse("AH 2C 2D 7S");
+        let board = parse("KH QH JH 9H 4C");
+        assert_eq!(
+            PokerHand::best_omaha(&hole, &board).unwrap().0,
+            PokerHand::OnePair(Rank::Two, [Rank::King, Rank::Queen, Rank::Jack])
+        );
+        assert!(PokerHand::best_omaha(&hole, &board[..4]).is_err());
+    }
+
+    #[test]
+    fn deck_test() {
+        let mut deck = Deck::new();
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.cards()[0], PokerCards(PokerCardSuit::Heart, Rank::Two));
+        assert!(deck.remove(&PokerCards(PokerCardSuit::Spades, Rank::Ace)));
+        assert!(!deck.remove(&PokerCards(PokerCardSuit::Spades, Rank::Ace)));
+        assert_eq!(
+            deck.deal(2),
+            Some(vec![
+                PokerCards(PokerCardSuit::Spades, Rank::King),
+                PokerCards(PokerCardSuit::Spades, Rank::Queen)
+            ])
+        );
+        assert_eq!(deck.len(), 49);
+        assert_eq!(deck.deal(50), None);
+        assert_eq!(deck.deal(49).map(|cards| cards.len()), Some(49));
+        assert!(deck.is_empty());
+
+        let mut a = Deck::new();
+        let mut b = Deck::new();
+        a.shuffle(42);
+        b.shuffle(42);
+        assert_eq!(a, b);
+        assert_ne!(a, Deck::new());
+        b.shuffle(42);
+        assert_ne!(a, b);
+        let full = Deck::new();
+        assert!(full.cards().iter().all(|card| a.cards().contains(card)));
+    }
+
+    #[test]
+    fn equity_test() {
+        let hole = [
+            [
+                PokerCards(PokerCardSuit::Heart, Rank::Ace),
+                PokerCards(PokerCardSuit::Diamond, Rank::Ace),
+            ],
+            [
+                PokerCards(PokerCardSuit::Heart, Rank::King),
+                PokerCards(PokerCardSuit::Diamond, Rank::King),
+            ],
+        ];
+        let close = |a: Equity, (win, tie): (f64, f64)| {
+            (a.win - win).abs() < 1e-12 && (a.tie - tie).abs() < 1e-12
+        };
+        let board = parse("2C 7S 9D 3C");
+        let equity = Poker::equity(&hole, &board, EquityMethod::Exhaustive).unwrap();
+        assert!(close(equity[0], (42. / 44., 0.)));
+        assert!(close(equity[1], (2. / 44., 0.)));
+
+        let board = parse("2C 7S 9D");
+        let equity = Poker::equity(&hole, &board, EquityMethod::Exhaustive).unwrap();
+        assert!((equity[0].win + equity[1].win + equity[0].tie - 1.).abs() < 1e-12);
+        assert!(close(equity[1], (equity[1].win, equity[0].tie)));
+        assert!(equity[1].win > 0.08 && equity[1].win < 0.1);
+
+        let board = parse("TC JC QC KC AC");
+        let equity = Poker::equity(&hole, &board, EquityMethod::Exhaustive).unwrap();
+        assert!(close(equity[0], (0., 1.)));
+
+        let method = EquityMethod::MonteCarlo {
+            trials: 20_000,
+            seed: 7,
+        };
+        let equity = Poker::equity(&hole, &[], method).unwrap();
+        let again = Poker::equity(&hole, &[], method).unwrap();
+        assert!(equity
+            .iter()
+            .zip(again)
+            .all(|(a, b)| close(*a, (b.win, b.tie))));
+        assert!((equity[0].win - 0.82).abs() < 0.02);
+
+        assert!(Poker::equity(&hole, &parse("AH 2C 3C"), EquityMethod::Exhaustive).is_err());
+        assert!(Poker::equity(&[], &board, EquityMethod::Exhaustive).is_err());
+        let method = EquityMethod::MonteCarlo { trials: 0, seed: 7 };
+        assert!(Poker::equity(&hole, &[], method).is_err());
+        let crowded = Deck::new()
+            .cards()
+            .chunks(2)
+            .take(24)
+            .map(|pair| [pair[0], pair[1]])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Poker::equity(&crowded, &[], EquityMethod::Exhaustive).map(|_| ()),
+            Err("Not enough cards for 24 players.".to_string())
+        );
     }
 }
@@ -0,0 +1,100 @@
+use std::ops::{Mul, Sub};
+
+use crate::isqrt;
+
+/// Counts the primes less than or equal to `x`, π(x).
+///
+/// Runs in `O(x^(3/4))` time and `O(x^(1/2))` memory, see [`prime_sum`].
+pub fn prime_count(x: u64) -> u64 {
+    lucy_hedgehog(x, |v| v.saturating_sub(1), |_| 1)
+}
+
+/// Sums the primes less than or equal to `x`.
+///
+/// Uses Lucy_Hedgehog's method: `S(v, p)` is the sum over the numbers in `2..=v` that are either
+/// prime or have no prime factor up to `p`. Starting from `S(v, 1)`, each prime `p` removes the
+/// numbers whose smallest prime factor is `p`,
+/// `S(v, p) = S(v, p - 1) - p * (S(v / p, p - 1) - S(p - 1, p - 1))`.
+/// Only the values `v = x / k` are needed, which are at most `2 * sqrt(x)`.
+pub fn prime_sum(x: u64) -> u128 {
+    lucy_hedgehog(
+        x,
+        |v| {
+            let v = u128::from(v);
+            (v * (v + 1) / 2).saturating_sub(1)
+        },
+        u128::from,
+    )
+}
+
+/// Computes `S(x, sqrt(x))`, where `initial(v)` is `S(v, 1)` and `weight(p)` is the value of the
+/// prime `p` in the sum.
+fn lucy_hedgehog<T>(x: u64, initial: impl Fn(u64) -> T, weight: impl Fn(u64) -> T) -> T
+where
+    T: Copy + PartialEq + Sub<Output = T> + Mul<Output = T>,
+{
+    let r = isqrt(x);
+    // `small[v]` holds `S(v)` for `v <= r`, `large[k]` holds `S(x / k)` for `k <= r`.
+    let mut small = (0..=r).map(&initial).collect::<Vec<_>>();
+    let mut large = (0..=r)
+        .map(|k| initial(x.checked_div(k).unwrap_or(0)))
+        .collect::<Vec<_>>();
+
+    for p in 2..=r {
+        if small[p as usize] == small[p as usize - 1] {
+            continue;
+        }
+        let below_p = small[p as usize - 1];
+        let w = weight(p);
+        let square = p * p;
+
+        for k in 1..=r.min(x / square) {
+            let d = k * p;
+            let s = if d <= r {
+                large[d as usize]
+            } else {
+                small[(x / d) as usize]
+            };
+            large[k as usize] = large[k as usize] - w * (s - below_p);
+        }
+        for v in (square..=r).rev() {
+            small[v as usize] = small[v as usize] - w * (small[(v / p) as usize] - below_p);
+        }
+    }
+
+    if x == 0 {
+        initial(0)
+    } else {
+        large[1]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::primes_up_to;
+
+    use super::*;
+
+    #[test]
+    fn prime_count_test() {
+        let primes = primes_up_to(3000);
+        for x in 0..3000 {
+            assert_eq!(
+                prime_count(x),
+                primes.iter().filter(|p| **p <= x).count() as u64
+            );
+            assert_eq!(
+                prime_sum(x),
+                primes
+                    .iter()
+                    .filter(|p| **p <= x)
+                    .map(|p| u128::from(*p))
+                    .sum::<u128>()
+            );
+        }
+        assert_eq!(prime_count(10_000_000), 664579);
+        assert_eq!(prime_count(10_000_000_000), 455052511);
+        assert_eq!(prime_sum(2_000_000), 142913828922);
+        assert_eq!(prime_sum(10_000_000_000), 2220822432581729238);
+    }
+}
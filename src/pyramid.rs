@@ -58,7 +58,7 @@ impl TryFrom<&[u64]> for Pyramid {
             .iter()
             .enumerate()
             .scan(0_usize, |off, (i, v)| {
-                if levels.peek().map_or(false, |l| i.eq(l)) {
+                if levels.peek().is_some_and(|l| i.eq(l)) {
                     levels.next();
                     *off = 0;
                 }
@@ -0,0 +1,451 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt::Display,
+    iter::{Product, Sum},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::Integer;
+
+/// Represents an exact rational number.
+///
+/// The value is always kept reduced and with a positive denominator, so the sign of a signed
+/// ratio is held by the numerator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Creates the ratio `numer / denom` reduced to its lowest terms.
+    ///
+    /// # Panic
+    /// Panics if `denom` is zero.
+    pub fn new(numer: T, denom: T) -> Self {
+        if denom == T::zero() {
+            panic!("Zero division.")
+        }
+        let (numer, denom) = if denom.is_negative() {
+            (T::zero() - numer, T::zero() - denom)
+        } else {
+            (numer, denom)
+        };
+        let factor = numer.gcd(&denom);
+        Self {
+            numer: numer / factor.clone(),
+            denom: denom / factor,
+        }
+    }
+
+    /// Creates the ratio `n / 1`.
+    pub fn from_integer(n: T) -> Self {
+        Self {
+            numer: n,
+            denom: T::one(),
+        }
+    }
+
+    /// Get the numerator.
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    /// Get the denominator.
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    /// Checks if the ratio is a whole number.
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    /// Get the reciprocal `denom / numer`.
+    ///
+    /// # Panic
+    /// Panics if the ratio is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.denom.clone(), self.numer.clone())
+    }
+
+    /// Get the mediant of two ratios, `(a + c) / (b + d)` for `a / b` and `c / d`.
+    pub fn mediant(&self, other: &Self) -> Self {
+        Self::new(
+            self.numer.clone() + other.numer.clone(),
+            self.denom.clone() + other.denom.clone(),
+        )
+    }
+
+    /// Get the ratio truncated towards zero.
+    pub fn trunc(&self) -> T {
+        self.numer.clone() / self.denom.clone()
+    }
+
+    /// Get the greatest integer less than or equal to the ratio.
+    pub fn floor(&self) -> T {
+        if self.numer.is_negative() && !self.is_integer() {
+            self.trunc() - T::one()
+        } else {
+            self.trunc()
+        }
+    }
+
+    /// Get the smallest integer greater than or equal to the ratio.
+    pub fn ceil(&self) -> T {
+        if !self.numer.is_negative() && !self.is_integer() {
+            self.trunc() + T::one()
+        } else {
+            self.trunc()
+        }
+    }
+
+    /// Get the nearest integer to the ratio, rounding half-way cases away from zero.
+    pub fn round(&self) -> T {
+        let remainder = (self.numer.clone() % self.denom.clone()).abs();
+        if remainder.clone() + remainder < self.denom {
+            self.trunc()
+        } else if self.numer.is_negative() {
+            self.trunc() - T::one()
+        } else {
+            self.trunc() + T::one()
+        }
+    }
+
+    /// Expands the ratio as a decimal number, detecting the repeating part.
+    pub fn decimal_expansion(&self) -> DecimalExpansion<T> {
+        self.expansion(10)
+    }
+
+    /// Expands the ratio in `base`, detecting the repeating part.
+    ///
+    /// # Panic
+    /// Panics if `base` is not between 2 and 36.
+    pub fn expansion(&self, base: u32) -> DecimalExpansion<T> {
+        if !(2..=36).contains(&base) {
+            panic!("Base must be between 2 and 36.")
+        }
+        let radix = T::from_u64(u64::from(base));
+        let numer = self.numer.clone().abs();
+        let integer = numer.clone() / self.denom.clone();
+
+        let mut seen = BTreeMap::new();
+        let mut digits = vec![];
+        let mut remainder = numer % self.denom.clone();
+        while remainder != T::zero() {
+            if let Some(start) = seen.get(&remainder) {
+                let period = digits.split_off(*start);
+                return DecimalExpansion {
+                    negative: self.numer.is_negative(),
+                    integer,
+                    pre_period: digits,
+                    period,
+                    base,
+                };
+            }
+            seen.insert(remainder.clone(), digits.len());
+
+            let mut shifted = remainder * radix.clone();
+            let mut digit = 0;
+            while shifted >= self.denom {
+                shifted = shifted - self.denom.clone();
+                digit += 1;
+            }
+            digits.push(digit);
+            remainder = shifted;
+        }
+        DecimalExpansion {
+            negative: self.numer.is_negative(),
+            integer,
+            pre_period: digits,
+            period: vec![],
+            base,
+        }
+    }
+}
+
+impl<T: Integer> From<T> for Ratio<T> {
+    fn from(n: T) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<T: Integer> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Ord for Ratio<T> {
+    /// Compares the continued fraction expansions term by term, so it never multiplies and can't
+    /// overflow.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numer.clone(), self.denom.clone());
+        let (mut c, mut d) = (other.numer.clone(), other.denom.clone());
+        loop {
+            let (q1, r1) = floor_div_rem(a, &b);
+            let (q2, r2) = floor_div_rem(c, &d);
+            match (q1.cmp(&q2), r1 == T::zero(), r2 == T::zero()) {
+                // `r1 / b < r2 / d` exactly when `d / r2 < b / r1`.
+                (Ordering::Equal, false, false) => ((a, b), (c, d)) = ((d, r2), (b, r1)),
+                (Ordering::Equal, r1_zero, r2_zero) => return r2_zero.cmp(&r1_zero),
+                (ordering, _, _) => return ordering,
+            }
+        }
+    }
+}
+
+/// Divides `a` by the positive `b`, rounding the quotient down so the remainder is non-negative.
+fn floor_div_rem<T: Integer>(a: T, b: &T) -> (T, T) {
+    let (q, r) = (a.clone() / b.clone(), a % b.clone());
+    if r.is_negative() {
+        (q - T::one(), r + b.clone())
+    } else {
+        (q, r)
+    }
+}
+
+impl<T: Integer> Display for Ratio<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+impl<T: Integer> Add for Ratio<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let factor = self.denom.gcd(&rhs.denom);
+        let l = rhs.denom.clone() / factor.clone();
+        let r = self.denom.clone() / factor;
+        Self::new(self.numer * l.clone() + rhs.numer * r, self.denom * l)
+    }
+}
+
+impl<T: Integer> Sub for Ratio<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let factor = self.denom.gcd(&rhs.denom);
+        let l = rhs.denom.clone() / factor.clone();
+        let r = self.denom.clone() / factor;
+        Self::new(self.numer * l.clone() - rhs.numer * r, self.denom * l)
+    }
+}
+
+impl<T: Integer> Mul for Ratio<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let l = self.numer.gcd(&rhs.denom);
+        let r = rhs.numer.gcd(&self.denom);
+        Self::new(
+            (self.numer / l.clone()) * (rhs.numer / r.clone()),
+            (self.denom / r) * (rhs.denom / l),
+        )
+    }
+}
+
+impl<T: Integer> Div for Ratio<T> {
+    type Output = Self;
+
+    /// # Panic
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.recip())
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Neg for Ratio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: Integer> Sum for Ratio<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(T::zero()), |sum, r| sum + r)
+    }
+}
+
+impl<T: Integer> Product for Ratio<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_integer(T::one()), |prod, r| prod * r)
+    }
+}
+
+/// The expansion of a rational number in a positional base, decimal by default.
+///
+/// The value is `integer.pre_period(period)`, where `period` repeats forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecimalExpansion<T> {
+    /// If the number is less than zero.
+    pub negative: bool,
+    /// The integer part of the absolute value.
+    pub integer: T,
+    /// The digits after the radix point that do not repeat.
+    pub pre_period: Vec<u8>,
+    /// The digits that repeat forever after `pre_period`, empty for a terminating expansion.
+    pub period: Vec<u8>,
+    /// The base of the digits, between 2 and 36.
+    pub base: u32,
+}
+
+impl<T: Integer> Display for DecimalExpansion<T> {
+    /// Formats the expansion with the repeating digits in parentheses, e.g. `0.1(6)` for `1/6`,
+    /// using the letters `a` to `z` for digits above 9.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let to_char = |d: u8| char::from_digit(u32::from(d), self.base).unwrap_or('?');
+        if self.negative {
+            write!(f, "-")?;
+        }
+        if self.base == 10 {
+            write!(f, "{}", self.integer)?;
+        } else {
+            let base = T::from_u64(u64::from(self.base));
+            let mut integer = self.integer.clone();
+            let mut digits = vec![];
+            loop {
+                let rem = integer.clone() % base.clone();
+                let digit = (0..self.base as u8)
+                    .find(|d| T::from_u64(u64::from(*d)) == rem)
+                    .unwrap_or_default();
+                digits.push(to_char(digit));
+                integer = integer / base.clone();
+                if integer == T::zero() {
+                    break;
+                }
+            }
+            write!(f, "{}", digits.into_iter().rev().collect::<String>())?;
+        }
+        if !self.pre_period.is_empty() || !self.period.is_empty() {
+            write!(f, ".")?;
+            for d in self.pre_period.iter() {
+                write!(f, "{}", to_char(*d))?;
+            }
+            if !self.period.is_empty() {
+                write!(f, "(")?;
+                for d in self.period.iter() {
+                    write!(f, "{}", to_char(*d))?;
+                }
+                write!(f, ")")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BigInt, BigUInt};
+
+    use super::*;
+
+    #[test]
+    fn ratio_new_test() {
+        assert_eq!(Ratio::new(2_u64, 4), Ratio::new(1, 2));
+        assert_eq!(Ratio::new(0_u64, 4), Ratio::from_integer(0));
+        assert_eq!(Ratio::new(3_i64, -6), Ratio::new(-1, 2));
+        assert_eq!(*Ratio::new(-3_i64, -6).numer(), 1);
+        assert_eq!(
+            Ratio::new(BigUInt::from(49_u64), BigUInt::from(98_u64)),
+            Ratio::new(BigUInt::from(1_u64), BigUInt::from(2_u64))
+        );
+    }
+
+    #[test]
+    fn ratio_arithmetic_test() {
+        let half = Ratio::new(1_u128, 2);
+        let third = Ratio::new(1_u128, 3);
+        assert_eq!(half.clone() + third.clone(), Ratio::new(5, 6));
+        assert_eq!(half.clone() - third.clone(), Ratio::new(1, 6));
+        assert_eq!(half.clone() * third.clone(), Ratio::new(1, 6));
+        assert_eq!(half.clone() / third.clone(), Ratio::new(3, 2));
+        assert_eq!(half.mediant(&third), Ratio::new(2, 5));
+        assert_eq!(
+            (1..=4).map(|n| Ratio::new(1_u64, n)).sum::<Ratio<_>>(),
+            Ratio::new(25, 12)
+        );
+        assert_eq!(
+            Ratio::new(BigInt::from(1_i64), BigInt::from(3_i64))
+                - Ratio::from_integer(BigInt::from(1_i64)),
+            Ratio::new(BigInt::from(-2_i64), BigInt::from(3_i64))
+        );
+        assert_eq!(-Ratio::new(1_i64, 2), Ratio::new(-1, 2));
+    }
+
+    #[test]
+    fn ratio_ord_test() {
+        assert!(Ratio::new(1_u64, 3) < Ratio::new(1, 2));
+        assert!(Ratio::new(-1_i64, 2) < Ratio::new(-1, 3));
+        assert_eq!(Ratio::new(2_u64, 6).cmp(&Ratio::new(1, 3)), Ordering::Equal);
+        assert!(Ratio::new(u64::MAX - 2, u64::MAX - 1) < Ratio::new(u64::MAX - 1, u64::MAX));
+        assert!(Ratio::new(u64::MAX, u64::MAX - 1) < Ratio::new(u64::MAX - 1, u64::MAX - 2));
+        assert!(Ratio::new(i64::MIN + 1, i64::MAX - 1) < Ratio::from_integer(-1));
+        assert!(Ratio::new(i64::MIN + 1, i64::MAX) == Ratio::from_integer(-1));
+        assert!(Ratio::new(-1_i64, i64::MAX) > Ratio::new(-1, i64::MAX - 1));
+        assert!(Ratio::new(3_i64, 2) > Ratio::new(4, 3));
+        assert!(Ratio::from_integer(2_u64) > Ratio::new(3, 2));
+
+        let ratios = (1..=8_i64)
+            .flat_map(|d| (-16..=16).map(move |n| Ratio::new(n, d)))
+            .collect::<Vec<_>>();
+        for l in &ratios {
+            for r in &ratios {
+                let expected = (l.numer() * r.denom()).cmp(&(r.numer() * l.denom()));
+                assert_eq!(l.cmp(r), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn ratio_rounding_test() {
+        assert_eq!(Ratio::new(7_i64, 2).floor(), 3);
+        assert_eq!(Ratio::new(7_i64, 2).ceil(), 4);
+        assert_eq!(Ratio::new(7_i64, 2).round(), 4);
+        assert_eq!(Ratio::new(-7_i64, 2).floor(), -4);
+        assert_eq!(Ratio::new(-7_i64, 2).ceil(), -3);
+        assert_eq!(Ratio::new(-7_i64, 2).round(), -4);
+        assert_eq!(Ratio::new(7_u64, 3).round(), 2);
+        assert_eq!(Ratio::new(6_u64, 3).ceil(), 2);
+    }
+
+    #[test]
+    fn ratio_display_test() {
+        assert_eq!(Ratio::new(-2_i64, 4).to_string(), "-1/2");
+        assert_eq!(
+            Ratio::new(BigUInt::from(6_u64), BigUInt::from(3_u64)).to_string(),
+            "2/1"
+        );
+    }
+
+    #[test]
+    fn decimal_expansion_test() {
+        assert_eq!(
+            Ratio::new(1_u64, 6).decimal_expansion().to_string(),
+            "0.1(6)"
+        );
+        assert_eq!(Ratio::new(1_u64, 4).decimal_expansion().to_string(), "0.25");
+        assert_eq!(
+            Ratio::new(22_u64, 7).decimal_expansion().to_string(),
+            "3.(142857)"
+        );
+        assert_eq!(
+            Ratio::new(-1_i64, 3).decimal_expansion().to_string(),
+            "-0.(3)"
+        );
+        assert_eq!(Ratio::new(5_u64, 1).decimal_expansion().to_string(), "5");
+        let expansion = Ratio::new(BigUInt::from(1_u64), BigUInt::from(28_u64)).decimal_expansion();
+        assert_eq!(expansion.pre_period, vec![0, 3]);
+        assert_eq!(expansion.period, vec![5, 7, 1, 4, 2, 8]);
+        assert_eq!(Ratio::new(5_u64, 3).expansion(2).to_string(), "1.(10)");
+        assert_eq!(Ratio::new(255_u64, 16).expansion(16).to_string(), "f.f");
+        assert_eq!(Ratio::new(-71_i64, 2).expansion(36).to_string(), "-z.i");
+    }
+}
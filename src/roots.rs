@@ -0,0 +1,199 @@
+use crate::{BigUInt, Integer};
+
+/// Integers that can have their roots taken exactly with [`iroot`].
+pub trait Roots: Integer {
+    /// Get the number of bits needed to represent the absolute value, `0` for zero.
+    fn bit_length(&self) -> u32;
+}
+
+/// Implements [`Roots`] for unsigned primitive integers.
+macro_rules! impl_unsigned_roots {
+    ($($t:ty),*) => {
+        $(
+            impl Roots for $t {
+                fn bit_length(&self) -> u32 {
+                    <$t>::BITS - self.leading_zeros()
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`Roots`] for signed primitive integers.
+macro_rules! impl_signed_roots {
+    ($($t:ty),*) => {
+        $(
+            impl Roots for $t {
+                fn bit_length(&self) -> u32 {
+                    <$t>::BITS - self.unsigned_abs().leading_zeros()
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_roots!(u8, u16, u32, u64, u128, usize);
+impl_signed_roots!(i8, i16, i32, i64, i128, isize);
+
+impl Roots for BigUInt {
+    fn bit_length(&self) -> u32 {
+        self.bits() as u32
+    }
+}
+
+/// Get the integer `k`-th root of `n`, the largest `r` where `r^k <= n`.
+///
+/// Negative numbers have odd roots rounded toward zero, so `iroot(-9, 3)` is `-2`.
+///
+/// # Panic
+/// Panics if `k` is zero, or if `n` is negative and `k` is even.
+///
+/// ```
+/// use project_euler::iroot;
+/// assert_eq!(iroot(1_000_000_u64, 3), 100);
+/// assert_eq!(iroot(999_999_u64, 3), 99);
+/// assert_eq!(iroot(u64::MAX, 2), u64::from(u32::MAX));
+/// ```
+pub fn iroot<T: Roots>(n: T, k: u32) -> T {
+    if k == 0 {
+        panic!("Zeroth root is undefined.")
+    } else if n.is_negative() {
+        if k.is_multiple_of(2) {
+            panic!("Even root of a negative number.")
+        }
+        return T::zero() - iroot(T::zero() - n, k);
+    }
+
+    let bits = n.bit_length();
+    if k == 1 || n <= T::one() {
+        return n;
+    } else if k >= bits {
+        return T::one();
+    }
+
+    // Newton's method, starting from a power of two above the root, decreases monotonically
+    // until it reaches the root.
+    let mut x = (0..bits.div_ceil(k)).fold(T::one(), |x, _| x.clone() + x);
+    let k_minus_one = T::from_u64(u64::from(k - 1));
+    let k = T::from_u64(u64::from(k));
+    loop {
+        // Dividing one step at a time keeps `n / x^(k - 1)` from overflowing.
+        let mut quotient = n.clone();
+        let mut i = T::zero();
+        while i < k_minus_one {
+            quotient = quotient / x.clone();
+            i = i + T::one();
+        }
+        if quotient >= x {
+            return x;
+        }
+        x = x.clone() - ((x - quotient - T::one()) / k.clone() + T::one());
+    }
+}
+
+/// Get the integer square root of `n`, the largest `r` where `r^2 <= n`.
+///
+/// # Panic
+/// Panics if `n` is negative.
+pub fn isqrt<T: Roots>(n: T) -> T {
+    iroot(n, 2)
+}
+
+/// Get the integer cube root of `n`, rounded toward zero.
+pub fn icbrt<T: Roots>(n: T) -> T {
+    iroot(n, 3)
+}
+
+/// Checks if `n` is `m^k` for some integer `m` and `k >= 2`.
+///
+/// ```
+/// use project_euler::is_perfect_power;
+/// assert!(is_perfect_power(1024_u64));
+/// assert!(is_perfect_power(-27_i32));
+/// assert!(!is_perfect_power(1000_u64 - 1));
+/// ```
+pub fn is_perfect_power<T: Roots>(n: T) -> bool {
+    if n.clone().abs() <= T::one() {
+        return true;
+    }
+    let bits = n.bit_length();
+    (2..bits)
+        .filter(|k| !n.is_negative() || k % 2 == 1)
+        .any(|k| {
+            let root = iroot(n.clone(), k);
+            (0..k).fold(T::one(), |power, _| power * root.clone()) == n
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iroot_test() {
+        for n in 0..=u16::MAX {
+            let root = isqrt(n);
+            assert!(u32::from(root).pow(2) <= u32::from(n));
+            assert!((u32::from(root) + 1).pow(2) > u32::from(n));
+        }
+        for n in 0..=255_u8 {
+            assert_eq!(isqrt(n), n.isqrt());
+            for k in 1..20 {
+                let root = iroot(n, k);
+                assert!(u64::from(root).pow(k) <= u64::from(n));
+                assert!((u64::from(root) + 1).pow(k) > u64::from(n));
+            }
+        }
+        assert_eq!(isqrt(u128::MAX), u128::from(u64::MAX));
+        assert_eq!(isqrt((1_u128 << 106) - 1), (1 << 53) - 1);
+        assert_eq!(isqrt(1_u64 << 62), 1 << 31);
+        assert_eq!(icbrt(u64::MAX), 2642245);
+        assert_eq!(icbrt(-27_i64), -3);
+        assert_eq!(icbrt(-26_i64), -2);
+        assert_eq!(icbrt(i8::MAX), 5);
+        assert_eq!(iroot(u64::MAX, 63), 2);
+        assert_eq!(iroot(u64::MAX, 64), 1);
+        assert_eq!(iroot(u64::MAX, 500), 1);
+        assert_eq!(iroot(i128::MAX, 127), 1);
+        assert_eq!(
+            isqrt(BigUInt::from(10_u64).pow(100)),
+            BigUInt::from(10_u64).pow(50)
+        );
+        assert_eq!(
+            isqrt(BigUInt::from(10_u64).pow(100) - BigUInt::from(1_u64)),
+            BigUInt::from(10_u64).pow(50) - BigUInt::from(1_u64)
+        );
+        assert_eq!(
+            iroot(BigUInt::from(3_u64).pow(500), 5),
+            BigUInt::from(3_u64).pow(100)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn iroot_negative_even_test() {
+        isqrt(-4_i32);
+    }
+
+    #[test]
+    fn is_perfect_power_test() {
+        let powers = (0..=1000_u32)
+            .filter(|n| is_perfect_power(*n))
+            .collect::<Vec<_>>();
+        let expected = (0..=1000_u32)
+            .filter(|n| {
+                *n <= 1 || (2..=31).any(|m: u32| (2..10).any(|k| m.checked_pow(k) == Some(*n)))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(powers, expected);
+        assert!(is_perfect_power(u64::from(u32::MAX) * u64::from(u32::MAX)));
+        assert!(!is_perfect_power(u64::MAX));
+        assert!(is_perfect_power(-32_i64));
+        assert!(!is_perfect_power(-4_i64));
+        assert!(is_perfect_power(-1_i64));
+        assert!(is_perfect_power(BigUInt::from(7_u64).pow(60)));
+        assert!(!is_perfect_power(
+            BigUInt::from(7_u64).pow(60) + BigUInt::from(1_u64)
+        ));
+    }
+}
@@ -0,0 +1,478 @@
+use std::{
+    ops::Range,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::{isqrt, NumberFactorSumClass};
+
+/// Residues modulo 30 that are coprime with 30, each is represented by a bit of a sieve byte.
+const WHEEL: [u64; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+/// Number of bytes sieved at a time, each byte covers 30 numbers.
+const SEGMENT_BYTES: usize = 32 * 1024;
+/// Amount of numbers covered by a segment.
+const SEGMENT_SPAN: u64 = SEGMENT_BYTES as u64 * 30;
+
+/// Lazy iterator over the primes in a range, using a segmented sieve of Eratosthenes.
+///
+/// The numbers coprime with 30 are bit-packed, 8 per byte, and sieved one segment at a time, so
+/// memory usage is bounded by the segment size plus the primes up to the square root of the end
+/// of the range.
+#[derive(Debug, Clone)]
+pub struct SegmentedSieve {
+    range: Range<u64>,
+    base_primes: Vec<u64>,
+    small_primes: std::vec::IntoIter<u64>,
+    segment: Vec<u8>,
+    segment_start: u64,
+    byte: usize,
+}
+
+impl SegmentedSieve {
+    /// Creates an iterator over the primes in `range`.
+    pub fn new(range: Range<u64>) -> Self {
+        let small_primes = [2, 3, 5]
+            .into_iter()
+            .filter(|p| range.contains(p))
+            .collect::<Vec<_>>()
+            .into_iter();
+        let base_primes = if range.is_empty() {
+            vec![]
+        } else {
+            simple_sieve(isqrt(range.end - 1))
+        };
+        let segment_start = range.start - range.start % 30;
+        let mut sieve = Self {
+            range,
+            base_primes,
+            small_primes,
+            segment: vec![],
+            segment_start,
+            byte: 0,
+        };
+        if !sieve.range.is_empty() {
+            sieve.sieve_segment();
+        }
+        sieve
+    }
+
+    /// Sieves the segment starting at `segment_start`.
+    fn sieve_segment(&mut self) {
+        let start = self.segment_start;
+        let end = start
+            .saturating_add(SEGMENT_SPAN)
+            .min(self.range.end.saturating_add(29) / 30 * 30);
+        self.segment.clear();
+        self.segment.resize(((end - start) / 30) as usize, 0xff);
+        self.byte = 0;
+
+        for p in self.base_primes.iter().skip(3) {
+            if p * p >= end {
+                break;
+            }
+            let first_multiplier = (*p).max(start.div_ceil(*p));
+            for residue in WHEEL {
+                // Multiples of `p` by numbers congruent to `residue` always land on the same bit.
+                let multiplier = first_multiplier + (residue + 30 - first_multiplier % 30) % 30;
+                let mut n = p * multiplier;
+                let bit = !(1 << wheel_index(n % 30));
+                while n < end {
+                    self.segment[((n - start) / 30) as usize] &= bit;
+                    n += 30 * p;
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for SegmentedSieve {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.small_primes.next() {
+            return Some(p);
+        }
+        loop {
+            while let Some(byte) = self.segment.get_mut(self.byte) {
+                if *byte == 0 {
+                    self.byte += 1;
+                    continue;
+                }
+                let bit = byte.trailing_zeros() as usize;
+                *byte &= *byte - 1;
+                let n = self.segment_start + 30 * self.byte as u64 + WHEEL[bit];
+                if n >= self.range.end {
+                    self.segment.clear();
+                    return None;
+                } else if n > 1 && n >= self.range.start {
+                    return Some(n);
+                }
+            }
+            if self.segment.is_empty() {
+                return None;
+            }
+            self.segment_start += SEGMENT_SPAN;
+            if self.segment_start >= self.range.end {
+                self.segment.clear();
+                return None;
+            }
+            self.sieve_segment();
+        }
+    }
+}
+
+/// Smallest span sieved when the cache of a `Primes` iterator grows.
+const PRIMES_INITIAL_SPAN: u64 = 1 << 16;
+
+/// Primes found so far by the `Primes` iterators sharing it.
+#[derive(Debug, Default)]
+struct PrimeCache {
+    primes: Vec<u64>,
+    sieved_up_to: u64,
+}
+
+impl PrimeCache {
+    /// Doubles the sieved range.
+    fn grow(&mut self) {
+        let end = (self.sieved_up_to * 2).max(PRIMES_INITIAL_SPAN);
+        self.primes
+            .extend(SegmentedSieve::new(self.sieved_up_to..end));
+        self.sieved_up_to = end;
+    }
+}
+
+/// Lazy infinite iterator over the primes.
+///
+/// Primes are sieved in segments that double in size as the iteration goes further. Clones share
+/// the same cache of primes, even across threads, so cloning is cheap and a prime is only sieved
+/// once.
+#[derive(Debug, Clone, Default)]
+pub struct Primes {
+    cache: Arc<RwLock<PrimeCache>>,
+    index: usize,
+}
+
+impl Primes {
+    /// Creates an iterator starting at 2.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the iterator so that the next prime yielded is the first prime greater or equal
+    /// to `n`.
+    pub fn skip_to(&mut self, n: u64) {
+        let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+        if cache.sieved_up_to > n {
+            self.index = cache.primes.partition_point(|p| *p < n);
+            return;
+        }
+        drop(cache);
+        let mut cache = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+        while cache.sieved_up_to <= n {
+            cache.grow();
+        }
+        self.index = cache.primes.partition_point(|p| *p < n);
+    }
+
+    /// Get the prime at `index`, growing the shared cache if needed.
+    ///
+    /// The cache only ever grows, so a lock poisoned by a panicking thread still holds valid
+    /// primes.
+    fn prime_at(&self, index: usize) -> u64 {
+        let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(p) = cache.primes.get(index) {
+            return *p;
+        }
+        drop(cache);
+        let mut cache = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+        while cache.primes.len() <= index {
+            cache.grow();
+        }
+        cache.primes[index]
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index += n + 1;
+        Some(self.prime_at(self.index - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Tables of multiplicative functions for every number up to a limit, built by a linear sieve.
+///
+/// Every composite is visited exactly once, through its smallest prime factor, so all tables are
+/// filled in `O(n)`.
+#[derive(Debug, Clone)]
+pub struct MultiplicativeSieve {
+    smallest_prime_factor: Vec<u32>,
+    totient: Vec<u32>,
+    mobius: Vec<i8>,
+    divisor_count: Vec<u32>,
+    divisor_sum: Vec<u64>,
+    primes: Vec<u32>,
+}
+
+impl MultiplicativeSieve {
+    /// Builds the tables for all numbers from 0 through `limit`.
+    ///
+    /// # Panic
+    /// Panics if `limit` does not fit in a `u32`.
+    pub fn new(limit: u64) -> Self {
+        let limit = u32::try_from(limit).expect("Limit must fit in a u32.") as usize;
+        let len = limit + 1;
+        let mut smallest_prime_factor = vec![0_u32; len];
+        let mut totient = vec![0_u32; len];
+        let mut mobius = vec![0_i8; len];
+        let mut divisor_count = vec![0_u32; len];
+        let mut divisor_sum = vec![0_u64; len];
+        // Power of the smallest prime factor that divides the number, and its exponent.
+        let mut prime_power = vec![0_u32; len];
+        let mut exponent = vec![0_u8; len];
+        let mut primes = vec![];
+
+        if limit >= 1 {
+            smallest_prime_factor[1] = 1;
+            totient[1] = 1;
+            mobius[1] = 1;
+            divisor_count[1] = 1;
+            divisor_sum[1] = 1;
+        }
+        for i in 2..len {
+            if smallest_prime_factor[i] == 0 {
+                smallest_prime_factor[i] = i as u32;
+                totient[i] = i as u32 - 1;
+                mobius[i] = -1;
+                divisor_count[i] = 2;
+                divisor_sum[i] = i as u64 + 1;
+                prime_power[i] = i as u32;
+                exponent[i] = 1;
+                primes.push(i as u32);
+            }
+            for p in primes.iter().map(|p| *p as usize) {
+                let j = i * p;
+                if j >= len || p > smallest_prime_factor[i] as usize {
+                    break;
+                }
+                smallest_prime_factor[j] = p as u32;
+                if p == smallest_prime_factor[i] as usize {
+                    totient[j] = totient[i] * p as u32;
+                    mobius[j] = 0;
+                    exponent[j] = exponent[i] + 1;
+                    prime_power[j] = prime_power[i] * p as u32;
+                    divisor_count[j] = divisor_count[i] / (u32::from(exponent[i]) + 1)
+                        * (u32::from(exponent[j]) + 1);
+                    let coprime_part = i / prime_power[i] as usize;
+                    divisor_sum[j] = divisor_sum[coprime_part]
+                        * ((u64::from(prime_power[j]) * p as u64 - 1) / (p as u64 - 1));
+                } else {
+                    totient[j] = totient[i] * (p as u32 - 1);
+                    mobius[j] = -mobius[i];
+                    exponent[j] = 1;
+                    prime_power[j] = p as u32;
+                    divisor_count[j] = divisor_count[i] * 2;
+                    divisor_sum[j] = divisor_sum[i] * (p as u64 + 1);
+                }
+            }
+        }
+
+        Self {
+            smallest_prime_factor,
+            totient,
+            mobius,
+            divisor_count,
+            divisor_sum,
+            primes,
+        }
+    }
+
+    /// Get the greatest number covered by the tables.
+    pub fn limit(&self) -> u64 {
+        self.totient.len() as u64 - 1
+    }
+
+    /// Get the primes up to the limit.
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.primes.iter().map(|p| u64::from(*p))
+    }
+
+    /// Get the smallest prime factor of `n`, `1` for `n = 1`.
+    pub fn smallest_prime_factor(&self, n: u64) -> u64 {
+        u64::from(self.smallest_prime_factor[n as usize])
+    }
+
+    /// Get Euler's totient, φ(n).
+    pub fn totient(&self, n: u64) -> u64 {
+        u64::from(self.totient[n as usize])
+    }
+
+    /// Get the Möbius function, μ(n).
+    pub fn mobius(&self, n: u64) -> i8 {
+        self.mobius[n as usize]
+    }
+
+    /// Get the number of divisors, σ₀(n).
+    pub fn divisor_count(&self, n: u64) -> u64 {
+        u64::from(self.divisor_count[n as usize])
+    }
+
+    /// Get the sum of divisors, σ₁(n).
+    pub fn divisor_sum(&self, n: u64) -> u64 {
+        self.divisor_sum[n as usize]
+    }
+
+    /// Get the aliquot sum, the sum of the divisors of `n` excluding itself.
+    pub fn aliquot_sum(&self, n: u64) -> u64 {
+        self.divisor_sum(n) - n
+    }
+
+    /// Get the class of every number from 1 through the limit.
+    pub fn classes(&self) -> impl Iterator<Item = (u64, NumberFactorSumClass)> + '_ {
+        (1..=self.limit()).map(|n| {
+            let aliquot_sum = u128::from(self.aliquot_sum(n));
+            (
+                n,
+                NumberFactorSumClass::from_aliquot_sum(u128::from(n), aliquot_sum),
+            )
+        })
+    }
+}
+
+/// Get the position of a residue in the wheel.
+fn wheel_index(residue: u64) -> usize {
+    WHEEL
+        .iter()
+        .position(|r| *r == residue)
+        .expect("Residue must be coprime with 30.")
+}
+
+/// Sieve of Eratosthenes over all numbers up to `limit`, used to get the sieving primes.
+fn simple_sieve(limit: u64) -> Vec<u64> {
+    let mut is_prime = vec![true; limit as usize + 1];
+    (2..=limit)
+        .filter(|n| {
+            if is_prime[*n as usize] {
+                (n * n..=limit)
+                    .step_by(*n as usize)
+                    .for_each(|m| is_prime[m as usize] = false);
+                true
+            } else {
+                false
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Factorization;
+
+    use super::*;
+
+    #[test]
+    fn segmented_sieve_test() {
+        assert_eq!(
+            SegmentedSieve::new(0..30).collect::<Vec<_>>(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        assert_eq!(SegmentedSieve::new(0..2).count(), 0);
+        assert_eq!(SegmentedSieve::new(5..5).count(), 0);
+        assert_eq!(
+            SegmentedSieve::new(90..110).collect::<Vec<_>>(),
+            vec![97, 101, 103, 107, 109]
+        );
+        assert_eq!(SegmentedSieve::new(0..1_000_000).count(), 78498);
+        assert_eq!(
+            SegmentedSieve::new(0..SEGMENT_SPAN * 3 + 7).collect::<Vec<_>>(),
+            simple_sieve(SEGMENT_SPAN * 3 + 6)
+        );
+        assert_eq!(
+            SegmentedSieve::new(10_000_000_000..10_000_000_100).collect::<Vec<_>>(),
+            vec![
+                10_000_000_019,
+                10_000_000_033,
+                10_000_000_061,
+                10_000_000_069,
+                10_000_000_097
+            ]
+        );
+    }
+
+    #[test]
+    fn multiplicative_sieve_test() {
+        let sieve = MultiplicativeSieve::new(5000);
+        assert_eq!(sieve.limit(), 5000);
+        assert_eq!(sieve.primes().count(), 669);
+        for n in 1..=5000 {
+            let f = Factorization::new(n);
+            assert_eq!(
+                sieve.smallest_prime_factor(n),
+                f.primes().next().unwrap_or(1)
+            );
+            assert_eq!(sieve.totient(n), f.totient());
+            assert_eq!(sieve.mobius(n), f.mobius());
+            assert_eq!(sieve.divisor_count(n), f.divisor_count());
+            assert_eq!(u128::from(sieve.divisor_sum(n)), f.divisor_sum());
+        }
+        assert_eq!(sieve.aliquot_sum(220), 284);
+        assert_eq!(
+            sieve
+                .classes()
+                .filter(|(_, c)| *c == NumberFactorSumClass::Perfect)
+                .map(|(n, _)| n)
+                .collect::<Vec<_>>(),
+            vec![6, 28, 496]
+        );
+        assert_eq!(MultiplicativeSieve::new(0).limit(), 0);
+    }
+
+    #[test]
+    fn primes_test() {
+        assert_eq!(
+            Primes::new().take(10).collect::<Vec<_>>(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        let mut primes = Primes::new();
+        assert_eq!(primes.nth(10_000), Some(104743));
+        assert_eq!(primes.next(), Some(104759));
+
+        let mut shared = primes.clone();
+        shared.skip_to(1_000_000);
+        assert_eq!(shared.next(), Some(1_000_003));
+        assert_eq!(primes.next(), Some(104761));
+        shared.skip_to(97);
+        assert_eq!(shared.next(), Some(97));
+        assert_eq!(
+            Primes::new().take_while(|p| *p < 1_000_000).count(),
+            SegmentedSieve::new(0..1_000_000).count()
+        );
+    }
+
+    #[test]
+    fn primes_threads_test() {
+        let primes = Primes::new();
+        let handles = (0..4)
+            .map(|i| {
+                let mut primes = primes.clone();
+                std::thread::spawn(move || primes.nth(10_000 * i))
+            })
+            .collect::<Vec<_>>();
+        let found = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![Some(2), Some(104743), Some(224743), Some(350381)]
+        );
+    }
+}
@@ -1,55 +1,144 @@
-/// Represents the mantissa of a fraction where 1 is the numerator.
+use std::fmt::Display;
+
+use crate::{greatest_common_divisor, multiplicative_order, DecimalExpansion};
+
+/// Represents the expansion of a fraction, by default `1/x` in base 10.
 #[derive(Debug, PartialEq, Clone)]
-pub struct UnitFraction(Vec<u8>);
+pub struct UnitFraction {
+    numerator: u64,
+    denominator: u64,
+    base: u64,
+    digits: Vec<u8>,
+}
 
 impl UnitFraction {
     /// Generates the unit fraction of `x` up to `precision` decimal places.
     pub fn new(x: u64, precision: usize) -> Self {
-        if x == 0 {
+        Self::from_fraction(1, x, 10, precision)
+    }
+
+    /// Generates the fraction `numerator / denominator` in `base` up to `precision` places.
+    ///
+    /// # Panic
+    /// Panics if `denominator` is zero or if `base` is not between 2 and 36.
+    pub fn from_fraction(numerator: u64, denominator: u64, base: u64, precision: usize) -> Self {
+        if denominator == 0 {
             panic!("Zero division.")
-        } else if x == 1 {
-            return Self(vec![]);
-        };
-
-        let mut res = vec![];
-        let mut numerator = 1;
-        while numerator > 0 && res.len() < precision {
-            numerator *= 10;
-            res.push((numerator / x) as u8);
-            numerator %= x;
+        } else if !(2..=36).contains(&base) {
+            panic!("Base must be between 2 and 36.")
+        }
+        let factor = greatest_common_divisor(&u128::from(numerator), &u128::from(denominator));
+        let (numerator, denominator) = (
+            numerator / factor.max(1) as u64,
+            denominator / factor.max(1) as u64,
+        );
+
+        let mut digits = vec![];
+        let mut remainder = u128::from(numerator % denominator);
+        while remainder > 0 && digits.len() < precision {
+            remainder *= u128::from(base);
+            digits.push((remainder / u128::from(denominator)) as u8);
+            remainder %= u128::from(denominator);
+        }
+        Self {
+            numerator,
+            denominator,
+            base,
+            digits,
         }
-        Self(res)
     }
 
     /// Get the precision of the UnitFraction
     pub fn precision(&self) -> usize {
-        self.0.len()
+        self.digits.len()
+    }
+
+    /// Get the digits after the radix point, up to the precision.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
     }
 
     /// Check if unit fraction is cyclic.
     pub fn is_cyclic(&self) -> bool {
-        self.get_cycle_length() > 0
+        self.period() > 0
     }
 
+    /// Get the length of the repeating part of the expansion.
     pub fn get_cycle_length(&self) -> usize {
-        for window_len in 1..self.precision() {
-            for window_start in 0..self.precision() {
-                if window_start + window_len > self.precision() {
-                    break;
-                } else if self.precision() - (window_start + window_len) < 2 * window_len {
-                    continue;
-                }
-                let window = self.0[window_start..(window_start + window_len)]
-                    .iter()
-                    .cycle();
-                let cmp_window = self.0[window_start..].iter();
-                if window.zip(cmp_window).all(|(l, r)| l.eq(r)) {
-                    return window_len;
-                }
+        self.period()
+    }
+
+    /// Get the number of digits after the radix point before the expansion starts repeating.
+    ///
+    /// This is the number of times the base has to divide the reduced denominator until they
+    /// become coprime.
+    pub fn pre_period(&self) -> usize {
+        self.pre_period_and_rest().0
+    }
+
+    /// Get the length of the repeating part of the expansion, `0` if the expansion terminates.
+    ///
+    /// This is the multiplicative order of the base modulo the part of the reduced denominator
+    /// coprime with the base.
+    pub fn period(&self) -> usize {
+        match self.pre_period_and_rest().1 {
+            1 => 0,
+            rest => multiplicative_order(self.base, rest).unwrap_or(0) as usize,
+        }
+    }
+
+    /// Get the digits of the repeating part of the expansion.
+    pub fn repetend(&self) -> Vec<u8> {
+        self.expansion_digits()
+            .skip(self.pre_period())
+            .take(self.period())
+            .collect()
+    }
+
+    /// Splits the reduced denominator into the number of steps needed to remove the factors it
+    /// shares with the base, and the remaining part that is coprime with the base.
+    fn pre_period_and_rest(&self) -> (usize, u64) {
+        let mut rest = self.denominator;
+        let mut steps = 0;
+        loop {
+            let factor = greatest_common_divisor(&u128::from(rest), &u128::from(self.base)) as u64;
+            if factor == 1 {
+                break (steps, rest);
             }
+            rest /= factor;
+            steps += 1;
         }
+    }
 
-        0
+    /// Generates the digits after the radix point without a precision limit.
+    fn expansion_digits(&self) -> impl Iterator<Item = u8> {
+        let base = u128::from(self.base);
+        let denominator = u128::from(self.denominator);
+        (0..).scan(
+            u128::from(self.numerator % self.denominator),
+            move |rem, _| {
+                *rem *= base;
+                let digit = (*rem / denominator) as u8;
+                *rem %= denominator;
+                Some(digit)
+            },
+        )
+    }
+}
+
+impl Display for UnitFraction {
+    /// Formats the expansion with the repeating digits in parentheses, e.g. `0.1(6)` for `1/6`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (pre_period, period) = (self.pre_period(), self.period());
+        let mut digits = self.expansion_digits();
+        DecimalExpansion {
+            negative: false,
+            integer: self.numerator / self.denominator,
+            pre_period: digits.by_ref().take(pre_period).collect(),
+            period: digits.take(period).collect(),
+            base: self.base as u32,
+        }
+        .fmt(f)
     }
 }
 
@@ -66,26 +155,26 @@ mod test {
 
     #[test]
     fn unit_fraction_test() {
-        assert_eq!(UnitFraction::from(1), UnitFraction(vec![]));
-        assert_eq!(UnitFraction::from(2), UnitFraction(vec![5]));
-        assert_eq!(UnitFraction::from(3), UnitFraction(vec![3; 64]));
-        assert_eq!(UnitFraction::from(4), UnitFraction(vec![2, 5]));
-        assert_eq!(UnitFraction::from(5), UnitFraction(vec![2]));
+        assert_eq!(UnitFraction::from(1).digits(), &[]);
+        assert_eq!(UnitFraction::from(2).digits(), vec![5]);
+        assert_eq!(UnitFraction::from(3).digits(), vec![3; 64]);
+        assert_eq!(UnitFraction::from(4).digits(), vec![2, 5]);
+        assert_eq!(UnitFraction::from(5).digits(), vec![2]);
         let mut u6 = vec![1];
         u6.extend(vec![6; 63]);
-        assert_eq!(UnitFraction::from(6), UnitFraction(u6));
-        let u7 = vec![1, 4, 2, 8, 5, 7]
+        assert_eq!(UnitFraction::from(6).digits(), u6);
+        let u7: Vec<_> = vec![1, 4, 2, 8, 5, 7]
             .into_iter()
             .cycle()
             .take(64)
             .collect();
-        assert_eq!(UnitFraction::from(7), UnitFraction(u7));
-        let u7 = vec![1, 4, 2, 8, 5, 7]
+        assert_eq!(UnitFraction::from(7).digits(), u7);
+        let u7: Vec<_> = vec![1, 4, 2, 8, 5, 7]
             .into_iter()
             .cycle()
             .take(10)
             .collect();
-        assert_eq!(UnitFraction::new(7, 10), UnitFraction(u7));
+        assert_eq!(UnitFraction::new(7, 10).digits(), u7);
     }
 
     #[test]
@@ -108,4 +197,40 @@ mod test {
         assert_eq!(UnitFraction::from(7).get_cycle_length(), 6);
         assert_eq!(UnitFraction::from(9).get_cycle_length(), 1);
     }
+
+    #[test]
+    fn period_test() {
+        assert_eq!(UnitFraction::from(12).pre_period(), 2);
+        assert_eq!(UnitFraction::from(12).period(), 1);
+        assert_eq!(UnitFraction::from(8).pre_period(), 3);
+        assert_eq!(UnitFraction::from(8).period(), 0);
+        assert_eq!(UnitFraction::from(7).repetend(), vec![1, 4, 2, 8, 5, 7]);
+        assert_eq!(UnitFraction::new(983, 1).period(), 982);
+        assert_eq!(UnitFraction::new(2 * 983, 1).pre_period(), 1);
+        assert_eq!(
+            UnitFraction::from_fraction(1, 3, 2, 64).repetend(),
+            vec![0, 1]
+        );
+        assert_eq!(UnitFraction::from_fraction(1, 3, 3, 64).period(), 0);
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(UnitFraction::from(1).to_string(), "1");
+        assert_eq!(UnitFraction::from(4).to_string(), "0.25");
+        assert_eq!(UnitFraction::from(6).to_string(), "0.1(6)");
+        assert_eq!(UnitFraction::from(7).to_string(), "0.(142857)");
+        assert_eq!(
+            UnitFraction::from_fraction(22, 7, 10, 0).to_string(),
+            "3.(142857)"
+        );
+        assert_eq!(
+            UnitFraction::from_fraction(5, 3, 2, 8).to_string(),
+            "1.(10)"
+        );
+        assert_eq!(
+            UnitFraction::from_fraction(255, 16, 16, 8).to_string(),
+            "f.f"
+        );
+    }
 }